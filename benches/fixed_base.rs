@@ -0,0 +1,32 @@
+use bls12_381::{G1Projective, G2Projective, Scalar};
+use criterion::{criterion_group, criterion_main, Criterion};
+use kzg_rs::fixed_base::{g1_generator_table, g2_generator_table, FixedBaseTable};
+
+/// The generator multiplications of single proof verification, `[y]G1` and `[z]G2`, with the
+/// cached tables against plain scalar multiplication.
+fn bench_generator_mul(c: &mut Criterion) {
+    let scalar = Scalar::from(0x1234_5678_9abc_def1u64).pow(&[0x0fed_cba9_8765_4321, 0, 0, 0]);
+
+    let mut group = c.benchmark_group("g1 generator");
+    group.bench_function("table", |b| b.iter(|| g1_generator_table().mul(&scalar)));
+    group.bench_function("scalar mul", |b| {
+        b.iter(|| G1Projective::generator() * scalar)
+    });
+    group.bench_function("build table", |b| {
+        b.iter(|| FixedBaseTable::new(G1Projective::generator()))
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("g2 generator");
+    group.bench_function("table", |b| b.iter(|| g2_generator_table().mul(&scalar)));
+    group.bench_function("scalar mul", |b| {
+        b.iter(|| G2Projective::generator() * scalar)
+    });
+    group.bench_function("build table", |b| {
+        b.iter(|| FixedBaseTable::new(G2Projective::generator()))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_generator_mul);
+criterion_main!(benches);
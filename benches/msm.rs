@@ -0,0 +1,38 @@
+use bls12_381::{G1Projective, Scalar};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use kzg_rs::msm::{msm_pippenger, msm_variable_base, MSM_NAIVE_THRESHOLD};
+
+/// Small MSMs, where `msm_variable_base` switches from naive summation to Pippenger at
+/// `MSM_NAIVE_THRESHOLD`.
+fn bench_small_msm(c: &mut Criterion) {
+    let mut group = c.benchmark_group(format!("msm (threshold {})", MSM_NAIVE_THRESHOLD));
+    for n in 2..=8 {
+        let points = (1..=n as u64)
+            .map(|i| G1Projective::generator() * Scalar::from(i))
+            .collect::<Vec<_>>();
+        let scalars = (1..=n as u64)
+            .map(|i| Scalar::from(i).pow(&[0x1234_5678_9abc_def1, 0, 0, 0]))
+            .collect::<Vec<_>>();
+
+        group.bench_with_input(BenchmarkId::new("naive", n), &n, |b, _| {
+            b.iter(|| {
+                points
+                    .iter()
+                    .zip(&scalars)
+                    .fold(G1Projective::identity(), |acc, (point, scalar)| {
+                        acc + point * scalar
+                    })
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("pippenger", n), &n, |b, _| {
+            b.iter(|| msm_pippenger(&points, &scalars))
+        });
+        group.bench_with_input(BenchmarkId::new("msm_variable_base", n), &n, |b, _| {
+            b.iter(|| msm_variable_base(&points, &scalars))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_small_msm);
+criterion_main!(benches);
@@ -0,0 +1,194 @@
+use crate::enums::KzgError;
+use crate::kzg_proof::{compute_powers, scalar_from_bytes_unchecked};
+use crate::msm::msm_variable_base;
+use crate::pairings::pairings_verify;
+use crate::POWERS_OF_TAU_DOMAIN;
+
+use alloc::{string::ToString, vec::Vec};
+use bls12_381::{G1Affine, G1Projective, G2Affine, G2Projective, Scalar};
+use sha2::{Digest, Sha256};
+
+/// Checks that the points are a well-formed setup in monomial form, `[tau^i]G1` and `[tau^i]G2`
+/// for one nonzero `tau`, as written by each contribution to a powers-of-tau ceremony.
+///
+/// Each of `e(g1[i + 1], G2) == e(g1[i], g2[1])` and `e(G1, g2[j + 1]) == e(g1[1], g2[j])` is
+/// folded into a single pairing check with a random linear combination, whose weights are
+/// derived from all of the points.
+pub fn verify_powers_of_tau(
+    g1_points: &[G1Affine],
+    g2_points: &[G2Affine],
+) -> Result<(), KzgError> {
+    if g1_points.len() < 2 || g2_points.len() < 2 {
+        return Err(KzgError::BadArgs(
+            "A setup needs at least two G1 and two G2 points".to_string(),
+        ));
+    }
+    if g1_points[0] != G1Affine::generator() || g2_points[0] != G2Affine::generator() {
+        return Err(KzgError::InvalidTrustedSetup(
+            "The setup does not start with the generators".to_string(),
+        ));
+    }
+    if bool::from(g1_points[1].is_identity()) {
+        return Err(KzgError::InvalidTrustedSetup(
+            "The setup has tau = 0".to_string(),
+        ));
+    }
+
+    let r_powers = compute_powers(
+        &compute_challenge(g1_points, g2_points),
+        g1_points.len().max(g2_points.len()) - 1,
+    );
+
+    let g1_points = g1_points.iter().map(G1Projective::from).collect::<Vec<_>>();
+    let n = g1_points.len() - 1;
+    let g1_lincomb = msm_variable_base(&g1_points[..n], &r_powers[..n]);
+    let g1_shifted_lincomb = msm_variable_base(&g1_points[1..], &r_powers[..n]);
+    if !pairings_verify(
+        g1_shifted_lincomb.into(),
+        G2Affine::generator(),
+        g1_lincomb.into(),
+        g2_points[1],
+    ) {
+        return Err(KzgError::InvalidTrustedSetup(
+            "The G1 points are not successive powers of tau".to_string(),
+        ));
+    }
+
+    let tau_g1 = g1_points[1].into();
+    let g2_points = g2_points.iter().map(G2Projective::from).collect::<Vec<_>>();
+    let n = g2_points.len() - 1;
+    let g2_lincomb = msm_variable_base(&g2_points[..n], &r_powers[..n]);
+    let g2_shifted_lincomb = msm_variable_base(&g2_points[1..], &r_powers[..n]);
+    if !pairings_verify(
+        G1Affine::generator(),
+        g2_shifted_lincomb.into(),
+        tau_g1,
+        g2_lincomb.into(),
+    ) {
+        return Err(KzgError::InvalidTrustedSetup(
+            "The G2 points are not successive powers of tau".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Checks that a ceremony contribution is a valid update of the previous setup: the new points
+/// are a well-formed setup, and its tau is the previous one times the contributor's secret `x`,
+/// whose proof of knowledge is `pot_pubkey = [x]G2`. That is, `e(new_g1[1], G2)` must equal
+/// `e(previous_g1[1], pot_pubkey)`.
+pub fn verify_contribution(
+    previous_g1_points: &[G1Affine],
+    new_g1_points: &[G1Affine],
+    new_g2_points: &[G2Affine],
+    pot_pubkey: &G2Affine,
+) -> Result<(), KzgError> {
+    if previous_g1_points.len() != new_g1_points.len() {
+        return Err(KzgError::BadArgs(format!(
+            "The previous setup has {} G1 points, but the new one has {}",
+            previous_g1_points.len(),
+            new_g1_points.len()
+        )));
+    }
+    verify_powers_of_tau(new_g1_points, new_g2_points)?;
+
+    if bool::from(pot_pubkey.is_identity()) {
+        return Err(KzgError::InvalidTrustedSetup(
+            "The contribution's secret is zero".to_string(),
+        ));
+    }
+    if !pairings_verify(
+        new_g1_points[1],
+        G2Affine::generator(),
+        previous_g1_points[1],
+        *pot_pubkey,
+    ) {
+        return Err(KzgError::InvalidTrustedSetup(
+            "The new setup does not build on the previous one".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Hashes the domain separator, the point counts and every point to the weight of the random
+/// linear combination.
+fn compute_challenge(g1_points: &[G1Affine], g2_points: &[G2Affine]) -> Scalar {
+    let mut hasher = Sha256::new();
+    hasher.update(POWERS_OF_TAU_DOMAIN);
+    hasher.update((g1_points.len() as u64).to_be_bytes());
+    hasher.update((g2_points.len() as u64).to_be_bytes());
+    for point in g1_points {
+        hasher.update(point.to_compressed());
+    }
+    for point in g2_points {
+        hasher.update(point.to_compressed());
+    }
+    scalar_from_bytes_unchecked(hasher.finalize().into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn powers_of_tau(tau: Scalar, n1: usize, n2: usize) -> (Vec<G1Affine>, Vec<G2Affine>) {
+        let g1_points = compute_powers(&tau, n1)
+            .iter()
+            .map(|power| (G1Affine::generator() * power).into())
+            .collect();
+        let g2_points = compute_powers(&tau, n2)
+            .iter()
+            .map(|power| (G2Affine::generator() * power).into())
+            .collect();
+        (g1_points, g2_points)
+    }
+
+    #[test]
+    fn test_verify_contribution() {
+        let (previous_g1, _) = powers_of_tau(Scalar::from(5), 16, 3);
+        let (new_g1, new_g2) = powers_of_tau(Scalar::from(35), 16, 3);
+        let pot_pubkey = (G2Affine::generator() * Scalar::from(7)).into();
+
+        verify_powers_of_tau(&new_g1, &new_g2).unwrap();
+        verify_contribution(&previous_g1, &new_g1, &new_g2, &pot_pubkey).unwrap();
+
+        // A different secret than the one proven
+        let wrong_pubkey = (G2Affine::generator() * Scalar::from(8)).into();
+        assert!(matches!(
+            verify_contribution(&previous_g1, &new_g1, &new_g2, &wrong_pubkey),
+            Err(KzgError::InvalidTrustedSetup(_))
+        ));
+
+        // A new setup that does not build on the previous one
+        assert!(matches!(
+            verify_contribution(&new_g1, &new_g1, &new_g2, &pot_pubkey),
+            Err(KzgError::InvalidTrustedSetup(_))
+        ));
+    }
+
+    #[test]
+    fn test_verify_powers_of_tau_rejects_tampering() {
+        let (g1_points, g2_points) = powers_of_tau(Scalar::from(35), 16, 3);
+
+        let mut tampered_g1 = g1_points.clone();
+        tampered_g1[9] = (G1Affine::generator() * Scalar::from(9)).into();
+        assert!(matches!(
+            verify_powers_of_tau(&tampered_g1, &g2_points),
+            Err(KzgError::InvalidTrustedSetup(_))
+        ));
+
+        let mut tampered_g2 = g2_points.clone();
+        tampered_g2[2] = (G2Affine::generator() * Scalar::from(9)).into();
+        assert!(matches!(
+            verify_powers_of_tau(&g1_points, &tampered_g2),
+            Err(KzgError::InvalidTrustedSetup(_))
+        ));
+
+        let (zero_g1, zero_g2) = powers_of_tau(Scalar::zero(), 16, 3);
+        assert!(verify_powers_of_tau(&zero_g1, &zero_g2).is_err());
+        assert!(matches!(
+            verify_powers_of_tau(&g1_points[..1], &g2_points),
+            Err(KzgError::BadArgs(_))
+        ));
+    }
+}
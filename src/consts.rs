@@ -8,11 +8,33 @@ pub const NUM_FIELD_ELEMENTS_PER_BLOB: usize = 4096;
 pub const BYTES_PER_BLOB: usize = NUM_FIELD_ELEMENTS_PER_BLOB * BYTES_PER_FIELD_ELEMENT;
 pub const BYTES_PER_COMMITMENT: usize = 48;
 pub const BYTES_PER_PROOF: usize = 48;
+/// Size of a record read by `KzgProof::verify_blob_kzg_proof_records`: a blob, then its
+/// commitment and its proof.
+pub const BYTES_PER_BLOB_RECORD: usize = BYTES_PER_BLOB + BYTES_PER_COMMITMENT + BYTES_PER_PROOF;
+/// Upper bound on the number of blobs accepted by a single batch verification.
+pub const MAX_BLOBS_PER_BATCH: usize = 1024;
 pub const DOMAIN_STR_LENGTH: usize = 16;
 pub const CHALLENGE_INPUT_SIZE: usize =
     DOMAIN_STR_LENGTH + 16 + BYTES_PER_BLOB + BYTES_PER_COMMITMENT;
+pub const BYTES_PER_COMPRESSED_KZG_SETTINGS: usize = NUM_ROOTS_OF_UNITY * BYTES_PER_FIELD_ELEMENT
+    + NUM_G1_POINTS * BYTES_PER_G1_POINT
+    + NUM_G2_POINTS * BYTES_PER_G2_POINT;
+/// Version byte that starts the versioned hash of a KZG commitment.
+pub const VERSIONED_HASH_VERSION_KZG: u8 = 0x01;
+/// Size of the point evaluation precompile input: the versioned hash, z, y, the commitment and
+/// the proof.
+pub const BYTES_PER_POINT_EVALUATION_INPUT: usize =
+    32 + 2 * BYTES_PER_FIELD_ELEMENT + BYTES_PER_COMMITMENT + BYTES_PER_PROOF;
+/// Compressed `g1_points[0]` of the Ethereum mainnet trusted setup, in Lagrange form.
+pub const MAINNET_G1_POINT_0: &str = "a0413c0dcafec6dbc9f47d66785cf1e8c981044f7d13cfe3e4fcbb71b5408dfde6312493cb3c1d30516cb3ca88c03654";
+/// Compressed `g2_points[0]` of the Ethereum mainnet trusted setup: the G2 generator.
+pub const MAINNET_G2_POINT_0: &str = "93e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8";
+/// Compressed `g2_points[1]` of the Ethereum mainnet trusted setup: the G2 generator times tau.
+pub const MAINNET_G2_POINT_1: &str = "b5bfd7dd8cdeb128843bc287230af38926187075cbfbefa81009a2ce615ac53d2914e5870cb452d2afaaab24f3499f72185cbfee53492714734429b7b38608e23926c911cceceac9a36851477ba4c60b087041de621000edc98edada20c1def2";
 pub const FIAT_SHAMIR_PROTOCOL_DOMAIN: &str = "FSBLOBVERIFY_V1_";
 pub const RANDOM_CHALLENGE_KZG_BATCH_DOMAIN: &str = "RCKZGBATCH___V1_";
+/// Domain separator for the random linear combination that checks a powers-of-tau setup.
+pub const POWERS_OF_TAU_DOMAIN: &str = "POWERSOFTAU_V1__";
 
 pub const SCALE2_ROOT_OF_UNITY: [[u64; 4]; 32] = [
     [
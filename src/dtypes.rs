@@ -1,13 +1,25 @@
 use crate::enums::KzgError;
-use crate::kzg_proof::safe_scalar_affine_from_bytes;
-use crate::{BYTES_PER_BLOB, BYTES_PER_FIELD_ELEMENT};
+use crate::kzg_proof::{
+    evaluate_polynomial_in_evaluation_form, safe_scalar_affine_from_bytes, scalar_to_bytes32,
+};
+use crate::trusted_setup::KzgSettings;
+use crate::utils::hex_to_bytes;
+use crate::{BYTES_PER_BLOB, BYTES_PER_FIELD_ELEMENT, NUM_FIELD_ELEMENTS_PER_BLOB};
 
-use alloc::{string::ToString, vec::Vec};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
 use bls12_381::Scalar;
+use core::fmt;
+use sha2::{Digest, Sha256};
 
 macro_rules! define_bytes_type {
     ($name:ident, $size:expr) => {
-        #[derive(Debug, Clone)]
+        /// Ordering compares the raw bytes lexicographically. For big-endian field elements this
+        /// is not the numeric order of the values, and for compressed points it has no meaning
+        /// beyond giving sorted collections a deterministic order.
+        #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
         pub struct $name([u8; $size]);
 
         impl $name {
@@ -25,6 +37,64 @@ macro_rules! define_bytes_type {
             pub fn as_slice(&self) -> &[u8] {
                 &self.0
             }
+
+            /// Parses a hex string of exactly the type's size, with or without a `0x` prefix.
+            pub fn from_hex(hex_str: &str) -> Result<Self, KzgError> {
+                Self::from_slice(&hex_to_bytes(hex_str)?)
+            }
+
+            /// Encodes the bytes as lowercase hex with a `0x` prefix.
+            pub fn to_hex(&self) -> String {
+                format!("{:#x}", self)
+            }
+
+            /// Copies the bytes out of a network buffer, which must be exactly the type's size.
+            #[cfg(feature = "bytes")]
+            pub fn from_bytes_buf(buf: &bytes::Bytes) -> Result<Self, KzgError> {
+                Self::from_slice(buf)
+            }
+        }
+
+        /// Like the integer formats, `{:#x}` adds a `0x` prefix and width and fill are honored.
+        impl fmt::LowerHex for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let hex: String = self.0.iter().map(|byte| format!("{:02x}", byte)).collect();
+                f.pad_integral(true, "0x", &hex)
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{:#x}", self)
+            }
+        }
+
+        impl AsRef<[u8]> for $name {
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+
+        impl core::ops::Deref for $name {
+            type Target = [u8];
+
+            fn deref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+
+        impl TryFrom<&[u8]> for $name {
+            type Error = KzgError;
+
+            fn try_from(slice: &[u8]) -> Result<Self, KzgError> {
+                Self::from_slice(slice)
+            }
+        }
+
+        impl From<[u8; $size]> for $name {
+            fn from(bytes: [u8; $size]) -> Self {
+                $name(bytes)
+            }
         }
 
         impl From<$name> for [u8; $size] {
@@ -32,6 +102,57 @@ macro_rules! define_bytes_type {
                 value.0
             }
         }
+
+        #[cfg(feature = "alloy")]
+        impl From<alloy_primitives::FixedBytes<$size>> for $name {
+            fn from(value: alloy_primitives::FixedBytes<$size>) -> Self {
+                $name(value.0)
+            }
+        }
+
+        #[cfg(feature = "alloy")]
+        impl From<$name> for alloy_primitives::FixedBytes<$size> {
+            fn from(value: $name) -> Self {
+                alloy_primitives::FixedBytes(value.0)
+            }
+        }
+
+        #[cfg(feature = "bytes")]
+        impl TryFrom<&bytes::Bytes> for $name {
+            type Error = KzgError;
+
+            fn try_from(buf: &bytes::Bytes) -> Result<Self, KzgError> {
+                Self::from_bytes_buf(buf)
+            }
+        }
+
+        #[cfg(feature = "bytes")]
+        impl From<$name> for bytes::Bytes {
+            fn from(value: $name) -> Self {
+                bytes::Bytes::copy_from_slice(&value.0)
+            }
+        }
+
+        #[cfg(feature = "zeroize")]
+        impl zeroize::Zeroize for $name {
+            fn zeroize(&mut self) {
+                self.0.zeroize();
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                crate::serde_hex::serialize(self, serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                crate::serde_hex::deserialize(deserializer)
+            }
+        }
     };
 }
 
@@ -39,17 +160,249 @@ define_bytes_type!(Bytes32, 32);
 define_bytes_type!(Bytes48, 48);
 define_bytes_type!(Blob, BYTES_PER_BLOB);
 
+/// Wraps a [`Bytes48`] in a type of its own, so that a commitment and a proof cannot be passed
+/// in each other's place.
+macro_rules! define_bytes48_newtype {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[cfg_attr(feature = "serde", serde(transparent))]
+        pub struct $name(pub Bytes48);
+
+        impl From<Bytes48> for $name {
+            fn from(bytes: Bytes48) -> Self {
+                $name(bytes)
+            }
+        }
+
+        impl From<$name> for Bytes48 {
+            fn from(value: $name) -> Bytes48 {
+                value.0
+            }
+        }
+
+        impl AsRef<Bytes48> for $name {
+            fn as_ref(&self) -> &Bytes48 {
+                &self.0
+            }
+        }
+    };
+}
+
+define_bytes48_newtype!(
+    /// A compressed KZG commitment.
+    KzgCommitment
+);
+define_bytes48_newtype!(
+    /// A compressed KZG proof.
+    KzgProofBytes
+);
+
+/// Conversions from and to the `c-kzg` type of the same bytes, for pipelines that mix both crates.
+#[cfg(feature = "c-kzg")]
+macro_rules! impl_c_kzg_conversions {
+    ($($name:ty => $c_kzg:ty),* $(,)?) => {$(
+        impl From<$c_kzg> for $name {
+            fn from(value: $c_kzg) -> Self {
+                (*value).into()
+            }
+        }
+
+        impl From<$name> for $c_kzg {
+            fn from(value: $name) -> Self {
+                <$c_kzg>::from(value.0)
+            }
+        }
+    )*};
+}
+
+#[cfg(feature = "c-kzg")]
+impl_c_kzg_conversions!(
+    Bytes32 => c_kzg::Bytes32,
+    Bytes48 => c_kzg::Bytes48,
+    Blob => c_kzg::Blob,
+);
+
+#[cfg(feature = "c-kzg")]
+impl From<c_kzg::KzgCommitment> for KzgCommitment {
+    fn from(value: c_kzg::KzgCommitment) -> Self {
+        KzgCommitment((*value).into())
+    }
+}
+
+#[cfg(feature = "c-kzg")]
+impl From<KzgCommitment> for c_kzg::KzgCommitment {
+    fn from(value: KzgCommitment) -> Self {
+        c_kzg::KzgCommitment::from(value.0 .0)
+    }
+}
+
+#[cfg(feature = "c-kzg")]
+impl From<c_kzg::KzgProof> for KzgProofBytes {
+    fn from(value: c_kzg::KzgProof) -> Self {
+        KzgProofBytes((*value).into())
+    }
+}
+
+#[cfg(feature = "c-kzg")]
+impl From<KzgProofBytes> for c_kzg::KzgProof {
+    fn from(value: KzgProofBytes) -> Self {
+        c_kzg::KzgProof::from(value.0 .0)
+    }
+}
+
+/// With the `zeroize` feature, a blob overwrites its bytes with zeros when dropped, as it may
+/// hold private data before it is published.
+#[cfg(feature = "zeroize")]
+impl Drop for Blob {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for Blob {}
+
 impl Blob {
+    /// Serializes exactly [`NUM_FIELD_ELEMENTS_PER_BLOB`] field elements as 32-byte big-endian
+    /// values, which are always canonical.
+    pub fn from_field_elements(elements: &[Scalar]) -> Result<Self, KzgError> {
+        if elements.len() != NUM_FIELD_ELEMENTS_PER_BLOB {
+            return Err(KzgError::InvalidBytesLength(format!(
+                "Expected {} field elements, but got {}",
+                NUM_FIELD_ELEMENTS_PER_BLOB,
+                elements.len()
+            )));
+        }
+
+        let mut bytes = [0u8; BYTES_PER_BLOB];
+        for (chunk, element) in bytes.chunks_mut(BYTES_PER_FIELD_ELEMENT).zip(elements) {
+            chunk.copy_from_slice(&scalar_to_bytes32(element));
+        }
+        Ok(Blob(bytes))
+    }
+
+    /// Reads exactly [`BYTES_PER_BLOB`] bytes straight into a blob, with no intermediate buffer.
+    /// Input that ends early is an `InvalidBytesLength` error.
+    #[cfg(feature = "std")]
+    pub fn from_reader<R: std::io::Read>(reader: &mut R) -> Result<Self, KzgError> {
+        let mut blob = Blob([0u8; BYTES_PER_BLOB]);
+        reader.read_exact(&mut blob.0).map_err(|e| match e.kind() {
+            std::io::ErrorKind::UnexpectedEof => KzgError::InvalidBytesLength(format!(
+                "The input ended before the {} bytes of a blob",
+                BYTES_PER_BLOB
+            )),
+            _ => KzgError::BadArgs(format!("Failed to read the blob: {}", e)),
+        })?;
+        Ok(blob)
+    }
+
     pub fn as_polynomial(&self) -> Result<Vec<Scalar>, KzgError> {
+        self.field_elements().collect()
+    }
+
+    /// Parses the field elements one at a time, in order, without allocating the polynomial.
+    pub fn field_elements(&self) -> impl Iterator<Item = Result<Scalar, KzgError>> + '_ {
         self.0
             .chunks(BYTES_PER_FIELD_ELEMENT)
-            .map(|slice| {
-                Bytes32::from_slice(slice).and_then(|bytes| safe_scalar_affine_from_bytes(&bytes))
-            })
+            .map(parse_field_element)
+    }
+
+    /// Parses the field element at index `i`.
+    pub fn field_element(&self, i: usize) -> Result<Scalar, KzgError> {
+        if i >= NUM_FIELD_ELEMENTS_PER_BLOB {
+            return Err(KzgError::BadArgs(format!(
+                "Invalid field element index {}, the blob has {} elements",
+                i, NUM_FIELD_ELEMENTS_PER_BLOB
+            )));
+        }
+
+        parse_field_element(&self.0[i * BYTES_PER_FIELD_ELEMENT..(i + 1) * BYTES_PER_FIELD_ELEMENT])
+    }
+
+    /// The SHA-256 digest of the blob's bytes.
+    ///
+    /// `Blob`'s `Hash` covers all of its bytes, so every lookup in a `HashMap<Blob, _>` reads
+    /// the whole blob. To look up many blobs, compute their content hashes once and key by those.
+    pub fn content_hash(&self) -> Bytes32 {
+        Bytes32(Sha256::digest(self.0).into())
+    }
+
+    /// Counts the field elements that are not all-zero bytes, without parsing them.
+    pub fn non_zero_element_count(&self) -> usize {
+        self.0
+            .chunks(BYTES_PER_FIELD_ELEMENT)
+            .filter(|element| element.iter().any(|&byte| byte != 0))
+            .count()
+    }
+
+    /// Returns the indices of every element that is not a canonical field element, scanning the
+    /// whole blob rather than stopping at the first as [`Blob::as_polynomial`] does.
+    pub fn find_invalid_elements(&self) -> Vec<usize> {
+        self.0
+            .chunks(BYTES_PER_FIELD_ELEMENT)
+            .enumerate()
+            .filter(|(_, slice)| parse_field_element(slice).is_err())
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Parses only the field elements with indices in `[start, end)`.
+    pub fn field_element_range(&self, start: usize, end: usize) -> Result<Vec<Scalar>, KzgError> {
+        if start > end || end > NUM_FIELD_ELEMENTS_PER_BLOB {
+            return Err(KzgError::BadArgs(format!(
+                "Invalid field element range {}..{}, the blob has {} elements",
+                start, end, NUM_FIELD_ELEMENTS_PER_BLOB
+            )));
+        }
+
+        self.0[start * BYTES_PER_FIELD_ELEMENT..end * BYTES_PER_FIELD_ELEMENT]
+            .chunks(BYTES_PER_FIELD_ELEMENT)
+            .map(parse_field_element)
             .collect()
     }
 }
 
+fn parse_field_element(slice: &[u8]) -> Result<Scalar, KzgError> {
+    Bytes32::from_slice(slice).and_then(|bytes| safe_scalar_affine_from_bytes(&bytes))
+}
+
+/// A polynomial in evaluation form: its values over the domain, in the bit-reversed order of the
+/// settings' roots of unity, as parsed from a blob.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PolynomialEval(Vec<Scalar>);
+
+impl PolynomialEval {
+    pub fn from_blob(blob: &Blob) -> Result<Self, KzgError> {
+        blob.as_polynomial().map(PolynomialEval)
+    }
+
+    /// Evaluates the polynomial at `x`, see [`evaluate_polynomial_in_evaluation_form`]. Borrows
+    /// the polynomial, so one blob can be evaluated at several points without parsing it again.
+    pub fn evaluate(&self, x: Scalar, kzg_settings: &KzgSettings) -> Result<Scalar, KzgError> {
+        evaluate_polynomial_in_evaluation_form(&self.0, x, kzg_settings)
+    }
+
+    pub fn into_inner(self) -> Vec<Scalar> {
+        self.0
+    }
+}
+
+impl From<Vec<Scalar>> for PolynomialEval {
+    fn from(evaluations: Vec<Scalar>) -> Self {
+        PolynomialEval(evaluations)
+    }
+}
+
+impl core::ops::Deref for PolynomialEval {
+    type Target = [Scalar];
+
+    fn deref(&self) -> &[Scalar] {
+        &self.0
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -58,9 +411,395 @@ mod tests {
         assert_eq!(bytes.0.len(), 32);
     }
 
+    #[test]
+    fn test_bytes_ordering() {
+        use crate::dtypes::{Bytes32, Bytes48};
+        use std::collections::BTreeMap;
+
+        let mut low = [0u8; 32];
+        low[31] = 0xff;
+        let mut high = [0u8; 32];
+        high[0] = 0x01;
+        let low = Bytes32::from_slice(&low).unwrap();
+        let high = Bytes32::from_slice(&high).unwrap();
+        assert!(low < high);
+        assert_eq!(low.cmp(&low.clone()), core::cmp::Ordering::Equal);
+
+        let commitments: BTreeMap<_, _> = [0x02u8, 0x00, 0x01]
+            .into_iter()
+            .map(|byte| (Bytes48::from_slice(&[byte; 48]).unwrap(), byte))
+            .collect();
+        assert_eq!(
+            commitments.values().copied().collect::<Vec<_>>(),
+            vec![0x00, 0x01, 0x02]
+        );
+    }
+
+    #[test]
+    fn test_bytes_hash() {
+        use crate::dtypes::{Blob, Bytes48};
+        use crate::BYTES_PER_BLOB;
+        use std::collections::{HashMap, HashSet};
+
+        let commitment = Bytes48::from_slice(&[1u8; 48]).unwrap();
+        let other = Bytes48::from_slice(&[2u8; 48]).unwrap();
+        let commitments: HashSet<_> = [commitment.clone(), other.clone(), commitment.clone()]
+            .into_iter()
+            .collect();
+        assert_eq!(commitments.len(), 2);
+        assert!(commitments.contains(&other));
+
+        // Blobs differing only in their last byte hash and compare as different keys
+        let blob = Blob::from_slice(&[0u8; BYTES_PER_BLOB]).unwrap();
+        let mut last_byte = [0u8; BYTES_PER_BLOB];
+        last_byte[BYTES_PER_BLOB - 1] = 1;
+        let other_blob = Blob::from_slice(&last_byte).unwrap();
+
+        let mut blobs = HashMap::new();
+        blobs.insert(blob.clone(), commitment);
+        blobs.insert(other_blob.clone(), other.clone());
+        blobs.insert(blob.clone(), other.clone());
+        assert_eq!(blobs.len(), 2);
+        assert_eq!(blobs[&blob], other);
+        assert_eq!(blobs[&other_blob], other);
+
+        assert_eq!(
+            blob.content_hash().to_hex(),
+            "0xfa43239bcee7b97ca62f007cc68487560a39e19f74f3dde7486db3f98df8e471"
+        );
+        assert_ne!(blob.content_hash(), other_blob.content_hash());
+    }
+
+    #[test]
+    fn test_bytes_conversions() {
+        use crate::dtypes::{Bytes32, Bytes48};
+        use crate::KzgError;
+
+        let bytes = (0..48).collect::<Vec<u8>>();
+        assert!(matches!(
+            Bytes48::try_from(&bytes[..47]),
+            Err(KzgError::InvalidBytesLength(_))
+        ));
+        let bytes48 = Bytes48::try_from(&bytes[..]).unwrap();
+        assert_eq!(bytes48.as_ref(), &bytes[..]);
+        assert_eq!(bytes48.len(), 48);
+
+        let bytes32 = Bytes32::from([7u8; 32]);
+        assert_eq!(&bytes32[..4], &[7, 7, 7, 7]);
+        assert_eq!(<[u8; 32]>::from(bytes32), [7u8; 32]);
+    }
+
+    #[test]
+    fn test_bytes_hex_round_trip() {
+        use crate::dtypes::{Blob, Bytes32, Bytes48};
+        use crate::{KzgError, BYTES_PER_BLOB};
+
+        let bytes32 = Bytes32::from_slice(&(0..32).collect::<Vec<u8>>()).unwrap();
+        let hex = bytes32.to_hex();
+        assert_eq!(
+            hex,
+            "0x000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"
+        );
+        assert_eq!(format!("{}", bytes32), hex);
+        assert_eq!(format!("{:#x}", bytes32), hex);
+        assert_eq!(format!("{:x}", bytes32), hex[2..]);
+        assert_eq!(format!("{:>68x}", bytes32), format!("    {}", &hex[2..]));
+        assert_eq!(Bytes32::from_hex(&hex).unwrap(), bytes32);
+        assert_eq!(Bytes32::from_hex(&hex[2..]).unwrap(), bytes32);
+
+        let bytes48 = Bytes48::from_slice(&[0xab; 48]).unwrap();
+        assert_eq!(Bytes48::from_hex(&bytes48.to_hex()).unwrap(), bytes48);
+
+        let blob = Blob::from_slice(&[0x5a; BYTES_PER_BLOB]).unwrap();
+        assert_eq!(Blob::from_hex(&format!("{:x}", blob)).unwrap(), blob);
+
+        assert!(matches!(
+            Bytes32::from_hex(&hex[..hex.len() - 2]),
+            Err(KzgError::InvalidBytesLength(_))
+        ));
+        assert!(matches!(
+            Bytes32::from_hex("0xzz"),
+            Err(KzgError::InvalidHexFormat(_))
+        ));
+    }
+
+    #[test]
+    fn test_find_invalid_elements() {
+        use crate::dtypes::Blob;
+        use crate::{BYTES_PER_BLOB, BYTES_PER_FIELD_ELEMENT};
+
+        let mut bytes = [0u8; BYTES_PER_BLOB];
+        let blob = Blob::from_slice(&bytes).unwrap();
+        assert!(blob.find_invalid_elements().is_empty());
+
+        // The modulus itself, and the largest 32-byte value
+        let modulus =
+            hex::decode("73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001")
+                .unwrap();
+        bytes[3 * BYTES_PER_FIELD_ELEMENT..4 * BYTES_PER_FIELD_ELEMENT].copy_from_slice(&modulus);
+        bytes[BYTES_PER_BLOB - BYTES_PER_FIELD_ELEMENT..].fill(0xff);
+        // One less than the modulus is canonical
+        bytes[10 * BYTES_PER_FIELD_ELEMENT..11 * BYTES_PER_FIELD_ELEMENT].copy_from_slice(&modulus);
+        bytes[11 * BYTES_PER_FIELD_ELEMENT - 1] = 0;
+
+        let blob = Blob::from_slice(&bytes).unwrap();
+        assert_eq!(blob.find_invalid_elements(), vec![3, 4095]);
+        assert!(blob.as_polynomial().is_err());
+    }
+
+    #[test]
+    fn test_polynomial_eval() {
+        use crate::dtypes::{Blob, PolynomialEval};
+        use crate::{KzgSettings, BYTES_PER_BLOB};
+        use bls12_381::Scalar;
+
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+        let mut bytes = [0u8; BYTES_PER_BLOB];
+        bytes[31] = 2;
+        bytes[63] = 3;
+        let blob = Blob::from_slice(&bytes).unwrap();
+
+        let polynomial = PolynomialEval::from_blob(&blob).unwrap();
+        assert_eq!(polynomial[..2], [Scalar::from(2), Scalar::from(3)]);
+        for i in [0, 1, 2] {
+            let expected = [Scalar::from(2), Scalar::from(3), Scalar::zero()][i];
+            let root = kzg_settings.roots_of_unity[i];
+            assert_eq!(polynomial.evaluate(root, &kzg_settings).unwrap(), expected);
+        }
+
+        let x = Scalar::from(0x1234u64);
+        assert_eq!(
+            polynomial.evaluate(x, &kzg_settings).unwrap(),
+            crate::kzg_proof::evaluate_polynomial_in_evaluation_form(
+                &blob.as_polynomial().unwrap(),
+                x,
+                &kzg_settings
+            )
+            .unwrap()
+        );
+        assert_eq!(polynomial.into_inner(), blob.as_polynomial().unwrap());
+    }
+
     #[test]
     fn test_bytes48() {
         let bytes = crate::dtypes::Bytes48::from_slice(&[0u8; 48]).unwrap();
         assert_eq!(bytes.0.len(), 48);
     }
+
+    #[test]
+    fn test_blob_field_element_range() {
+        use crate::{dtypes::Blob, BYTES_PER_BLOB, NUM_FIELD_ELEMENTS_PER_BLOB};
+
+        let mut bytes = [0u8; BYTES_PER_BLOB];
+        for (i, element) in bytes.chunks_mut(32).enumerate() {
+            element[30..].copy_from_slice(&(i as u16).to_be_bytes());
+        }
+        let blob = Blob::from_slice(&bytes).unwrap();
+        let polynomial = blob.as_polynomial().unwrap();
+
+        assert_eq!(blob.field_element_range(3, 10).unwrap(), polynomial[3..10]);
+        assert_eq!(
+            blob.field_element_range(0, NUM_FIELD_ELEMENTS_PER_BLOB)
+                .unwrap(),
+            polynomial
+        );
+        assert!(blob.field_element_range(5, 5).unwrap().is_empty());
+        assert!(blob.field_element_range(6, 5).is_err());
+        assert!(blob
+            .field_element_range(0, NUM_FIELD_ELEMENTS_PER_BLOB + 1)
+            .is_err());
+    }
+
+    #[test]
+    fn test_blob_field_elements() {
+        use crate::{dtypes::Blob, BYTES_PER_BLOB, NUM_FIELD_ELEMENTS_PER_BLOB};
+
+        let mut bytes = [0u8; BYTES_PER_BLOB];
+        for (i, element) in bytes.chunks_mut(32).enumerate() {
+            element[30..].copy_from_slice(&(i as u16).to_be_bytes());
+        }
+        let blob = Blob::from_slice(&bytes).unwrap();
+        let polynomial = blob.as_polynomial().unwrap();
+
+        assert_eq!(blob.field_elements().count(), NUM_FIELD_ELEMENTS_PER_BLOB);
+        for (i, (element, expected)) in blob.field_elements().zip(&polynomial).enumerate() {
+            assert_eq!(element.unwrap(), *expected);
+            assert_eq!(blob.field_element(i).unwrap(), *expected);
+        }
+        assert!(blob.field_element(NUM_FIELD_ELEMENTS_PER_BLOB).is_err());
+
+        // A non-canonical element fails only at its own position
+        bytes[32..64].fill(0xff);
+        let blob = Blob::from_slice(&bytes).unwrap();
+        assert!(blob.field_element(0).is_ok());
+        assert!(blob.field_element(1).is_err());
+        assert!(blob.field_elements().nth(1).unwrap().is_err());
+    }
+
+    #[test]
+    fn test_blob_from_field_elements() {
+        use crate::{dtypes::Blob, enums::KzgError, BYTES_PER_BLOB};
+
+        let mut bytes = [0u8; BYTES_PER_BLOB];
+        for (i, element) in bytes.chunks_mut(32).enumerate() {
+            element[28..].copy_from_slice(&(i as u32).wrapping_mul(0x0102_0304).to_be_bytes());
+        }
+        let blob = Blob::from_slice(&bytes).unwrap();
+
+        let polynomial = blob.as_polynomial().unwrap();
+        assert_eq!(Blob::from_field_elements(&polynomial).unwrap(), blob);
+        assert!(matches!(
+            Blob::from_field_elements(&polynomial[1..]),
+            Err(KzgError::InvalidBytesLength(_))
+        ));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_blob_from_reader() {
+        use crate::{dtypes::Blob, enums::KzgError, BYTES_PER_BLOB};
+        use std::io::Cursor;
+
+        let bytes = (0..BYTES_PER_BLOB + 1)
+            .map(|i| (i % 251) as u8)
+            .collect::<Vec<_>>();
+        let mut reader = Cursor::new(&bytes);
+        let blob = Blob::from_reader(&mut reader).unwrap();
+        assert_eq!(blob.as_slice(), &bytes[..BYTES_PER_BLOB]);
+        // Only the blob's bytes are consumed
+        assert_eq!(reader.position(), BYTES_PER_BLOB as u64);
+
+        let mut truncated = Cursor::new(&bytes[..BYTES_PER_BLOB - 1]);
+        assert!(matches!(
+            Blob::from_reader(&mut truncated),
+            Err(KzgError::InvalidBytesLength(_))
+        ));
+    }
+
+    #[test]
+    fn test_blob_non_zero_element_count() {
+        use crate::{dtypes::Blob, BYTES_PER_BLOB};
+
+        let mut bytes = [0u8; BYTES_PER_BLOB];
+        assert_eq!(
+            Blob::from_slice(&bytes).unwrap().non_zero_element_count(),
+            0
+        );
+
+        bytes[31] = 1;
+        bytes[32 * 100] = 1;
+        bytes[BYTES_PER_BLOB - 1] = 1;
+        assert_eq!(
+            Blob::from_slice(&bytes).unwrap().non_zero_element_count(),
+            3
+        );
+    }
+
+    #[cfg(feature = "alloy")]
+    #[test]
+    fn test_alloy_conversions() {
+        use crate::{dtypes::Blob, dtypes::Bytes48, BYTES_PER_BLOB};
+        use alloy_primitives::FixedBytes;
+
+        let bytes = FixedBytes::<48>::repeat_byte(0xab);
+        let converted = Bytes48::from(bytes);
+        assert_eq!(converted.as_slice(), bytes.as_slice());
+        assert_eq!(FixedBytes::<48>::from(converted), bytes);
+
+        let blob = FixedBytes::<BYTES_PER_BLOB>::repeat_byte(0x01);
+        assert_eq!(FixedBytes::from(Blob::from(blob)), blob);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_hex_round_trip() {
+        use crate::dtypes::{Blob, Bytes48, KzgCommitment};
+        use crate::kzg_proof::tests::{BlobInput, Test};
+        use serde_derive::{Deserialize, Serialize};
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Fixture {
+            blob: Blob,
+            commitment: KzgCommitment,
+            #[serde(with = "crate::serde_hex")]
+            proof: Bytes48,
+        }
+
+        let data = include_str!("../tests/verify_blob_kzg_proof/verify_blob_kzg_proof_case_correct_proof_fb324bc819407148/data.yaml");
+        let input = serde_yaml::from_str::<serde_yaml::Value>(data).unwrap()["input"].clone();
+        let fixture: Fixture = serde_yaml::from_value(input).unwrap();
+
+        let test: Test<BlobInput> = serde_yaml::from_str(data).unwrap();
+        assert_eq!(fixture.blob, test.input.get_blob().unwrap());
+        assert_eq!(fixture.commitment.0, test.input.get_commitment().unwrap());
+        assert_eq!(fixture.proof, test.input.get_proof().unwrap());
+
+        let yaml = serde_yaml::to_string(&fixture).unwrap();
+        assert!(yaml.contains(&fixture.commitment.0.to_hex()));
+        assert_eq!(serde_yaml::from_str::<Fixture>(&yaml).unwrap(), fixture);
+
+        // The length must match the type
+        assert!(serde_yaml::from_str::<Bytes48>("'0x0102'").is_err());
+        assert!(serde_yaml::from_str::<Bytes48>("'0xzz'").is_err());
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn test_zeroize() {
+        use crate::dtypes::{Blob, Bytes32};
+        use crate::BYTES_PER_BLOB;
+        use zeroize::Zeroize;
+
+        let mut blob = Blob::from([0xab; BYTES_PER_BLOB]);
+        blob.zeroize();
+        assert!(blob.iter().all(|&byte| byte == 0));
+
+        let mut bytes = Bytes32::from([0xcd; 32]);
+        bytes.zeroize();
+        assert_eq!(bytes, Bytes32::from([0; 32]));
+    }
+
+    #[cfg(feature = "bytes")]
+    #[test]
+    fn test_bytes_buf_conversions() {
+        use crate::dtypes::{Blob, Bytes48};
+        use crate::{KzgError, BYTES_PER_BLOB};
+        use bytes::Bytes;
+
+        let buf = Bytes::from(vec![0x42; BYTES_PER_BLOB]);
+        let blob = Blob::from_bytes_buf(&buf).unwrap();
+        assert_eq!(blob.as_slice(), &buf[..]);
+        assert_eq!(Bytes::from(blob), buf);
+
+        let buf = Bytes::from_static(&[0x01; 48]);
+        assert_eq!(Bytes48::try_from(&buf).unwrap().as_slice(), &buf[..]);
+        assert!(matches!(
+            Bytes48::from_bytes_buf(&buf.slice(1..)),
+            Err(KzgError::InvalidBytesLength(_))
+        ));
+    }
+
+    #[cfg(feature = "c-kzg")]
+    #[test]
+    fn test_c_kzg_conversions() {
+        use crate::dtypes::{Blob, Bytes32, Bytes48, KzgCommitment};
+        use crate::kzg_proof::tests::{BlobInput, Test};
+
+        let data = include_str!("../tests/verify_blob_kzg_proof/verify_blob_kzg_proof_case_correct_proof_fb324bc819407148/data.yaml");
+        let test: Test<BlobInput> = serde_yaml::from_str(data).unwrap();
+        let blob = test.input.get_blob().unwrap();
+        let commitment = test.input.get_commitment().unwrap();
+
+        let c_kzg_blob = c_kzg::Blob::from(blob.clone());
+        assert_eq!(&c_kzg_blob[..], blob.as_slice());
+        assert_eq!(Blob::from(c_kzg_blob), blob);
+
+        let c_kzg_bytes = c_kzg::Bytes48::from(commitment.clone());
+        assert_eq!(Bytes48::from(c_kzg_bytes), commitment);
+        let c_kzg_commitment = c_kzg::KzgCommitment::from(KzgCommitment(commitment.clone()));
+        assert_eq!(KzgCommitment::from(c_kzg_commitment).0, commitment);
+
+        let bytes = Bytes32::from([0x2a; 32]);
+        assert_eq!(Bytes32::from(c_kzg::Bytes32::from(bytes.clone())), bytes);
+    }
 }
@@ -15,6 +15,11 @@ pub enum KzgError {
     InvalidHexFormat(String),
     /// The provided trusted setup params are invalid.
     InvalidTrustedSetup(String),
+    /// More inputs were supplied than a single call accepts.
+    TooManyInputs(String),
+    /// The inputs are well-formed, but the proof does not verify. Only the `_strict` verify
+    /// functions return this; the others return `Ok(false)`.
+    VerificationFailed(String),
 }
 
 impl fmt::Display for KzgError {
@@ -23,8 +28,34 @@ impl fmt::Display for KzgError {
             Self::BadArgs(s)
             | Self::InvalidBytesLength(s)
             | Self::InvalidHexFormat(s)
-            | Self::InvalidTrustedSetup(s) => f.write_str(s),
+            | Self::InvalidTrustedSetup(s)
+            | Self::TooManyInputs(s) => f.write_str(s),
+            Self::VerificationFailed(s) => write!(f, "Verification failed: {}", s),
             Self::InternalError => f.write_str("Internal error"),
         }
     }
 }
+
+#[cfg(feature = "std")]
+impl std::error::Error for KzgError {}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kzg_error_into_boxed_error() {
+        fn parse() -> Result<(), Box<dyn std::error::Error>> {
+            Err(KzgError::BadArgs("Invalid commitment".into()))?;
+            Ok(())
+        }
+
+        let error = parse().unwrap_err();
+        assert_eq!(error.to_string(), "Invalid commitment");
+        assert!(error.source().is_none());
+        assert!(matches!(
+            error.downcast_ref::<KzgError>(),
+            Some(KzgError::BadArgs(_))
+        ));
+    }
+}
@@ -0,0 +1,124 @@
+use crate::enums::KzgError;
+
+use bls12_381::Scalar;
+
+/// Evaluates the polynomial with coefficients `values` at `roots`, in place. `roots` must be the
+/// powers `w^0, ..., w^(n-1)` of an `n`-th root of unity `w`, where `n` is the power-of-two length
+/// of `values`. Both the coefficients and the evaluations are in natural order.
+///
+/// The settings' roots of unity are bit-reversed, so pass them through
+/// [`crate::utils::bit_reversal_permutation`] first.
+pub fn fft(values: &mut [Scalar], roots: &[Scalar]) -> Result<(), KzgError> {
+    let n = values.len();
+    if !n.is_power_of_two() || roots.len() != n {
+        return Err(KzgError::BadArgs(format!(
+            "Expected a power of two of values and as many roots, but got {} and {}",
+            n,
+            roots.len()
+        )));
+    }
+    if n == 1 {
+        return Ok(());
+    }
+
+    // Iterative Cooley-Tukey, which starts from the bit-reversed order of the inputs
+    let log_n = n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - log_n);
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let half = len / 2;
+        let step = n / len;
+        for start in (0..n).step_by(len) {
+            for j in 0..half {
+                let t = values[start + j + half] * roots[j * step];
+                values[start + j + half] = values[start + j] - t;
+                values[start + j] += t;
+            }
+        }
+        len *= 2;
+    }
+    Ok(())
+}
+
+/// The inverse of [`fft`], with the same `roots`: interpolates the coefficients of the polynomial
+/// with the evaluations `values`, in place.
+pub fn ifft(values: &mut [Scalar], roots: &[Scalar]) -> Result<(), KzgError> {
+    let n = values.len();
+    fft(values, roots)?;
+    if n == 1 {
+        return Ok(());
+    }
+
+    // Evaluating at w^-i instead of w^i reverses all but the first evaluation
+    values[1..].reverse();
+    let n_inv = Scalar::from(n as u64).invert().unwrap();
+    for value in values.iter_mut() {
+        *value *= n_inv;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kzg_proof::compute_powers;
+    use crate::utils::{bit_reversal_permutation, compute_roots_of_unity};
+
+    #[test]
+    fn test_fft_matches_naive_evaluation() {
+        for n in [1, 2, 8, 64] {
+            let roots = match n {
+                1 => vec![Scalar::one()],
+                _ => bit_reversal_permutation(&compute_roots_of_unity(n).unwrap()).unwrap(),
+            };
+            let coefficients = compute_powers(&Scalar::from(0xdead_beefu64), n);
+
+            let mut values = coefficients.clone();
+            fft(&mut values, &roots).unwrap();
+            for (value, root) in values.iter().zip(&roots) {
+                let expected = coefficients
+                    .iter()
+                    .rev()
+                    .fold(Scalar::zero(), |acc, coefficient| acc * root + coefficient);
+                assert_eq!(*value, expected);
+            }
+
+            ifft(&mut values, &roots).unwrap();
+            assert_eq!(values, coefficients);
+        }
+
+        let roots = bit_reversal_permutation(&compute_roots_of_unity(8).unwrap()).unwrap();
+        assert!(fft(&mut [Scalar::one(); 6], &roots[..6]).is_err());
+        assert!(fft(&mut [Scalar::one(); 8], &roots[..4]).is_err());
+        assert!(ifft(&mut [], &[]).is_err());
+    }
+
+    #[test]
+    fn test_fft_of_blob_coefficients() {
+        use crate::kzg_proof::{lagrange_to_monomial, tests::BlobInput, tests::Test};
+        use crate::KzgSettings;
+
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+        let data = include_str!("../tests/verify_blob_kzg_proof/verify_blob_kzg_proof_case_correct_proof_fb324bc819407148/data.yaml");
+        let test: Test<BlobInput> = serde_yaml::from_str(data).unwrap();
+        let evaluations = test.input.get_blob().unwrap().as_polynomial().unwrap();
+
+        // The blob's coefficients evaluate back to the blob over the natural-order domain
+        let mut values = lagrange_to_monomial(&evaluations, &kzg_settings).unwrap();
+        let roots = bit_reversal_permutation(&kzg_settings.roots_of_unity).unwrap();
+        fft(&mut values, &roots).unwrap();
+        assert_eq!(bit_reversal_permutation(&values).unwrap(), evaluations);
+
+        ifft(&mut values, &roots).unwrap();
+        assert_eq!(
+            values,
+            lagrange_to_monomial(&evaluations, &kzg_settings).unwrap()
+        );
+    }
+}
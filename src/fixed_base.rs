@@ -0,0 +1,91 @@
+use alloc::vec::Vec;
+use bls12_381::{G1Projective, G2Projective, Scalar};
+use core::ops::AddAssign;
+use group::{Curve, Group};
+use spin::Once;
+
+const WINDOW_BITS: usize = 4;
+const WINDOW_SIZE: usize = 1 << WINDOW_BITS;
+const NUM_WINDOWS: usize = 256 / WINDOW_BITS;
+
+/// Precomputed multiples `j * 16^i * B` of a fixed base `B`, for every 4-bit window `i` of a
+/// scalar and every nonzero digit `j`. A multiplication then costs one mixed addition per nonzero
+/// digit and no doublings.
+pub struct FixedBaseTable<G: Curve> {
+    table: Vec<G::AffineRepr>,
+}
+
+impl<G> FixedBaseTable<G>
+where
+    G: Curve + Group<Scalar = Scalar> + AddAssign<G::AffineRepr>,
+    G::AffineRepr: Copy + Default,
+{
+    pub fn new(base: G) -> Self {
+        let mut table = Vec::with_capacity(NUM_WINDOWS * (WINDOW_SIZE - 1));
+        let mut window_base = base;
+        for _ in 0..NUM_WINDOWS {
+            let mut multiple = window_base;
+            for _ in 1..WINDOW_SIZE {
+                table.push(multiple);
+                multiple += window_base;
+            }
+            // `multiple` is now 16 times the base of this window
+            window_base = multiple;
+        }
+
+        let mut affine_table = vec![G::AffineRepr::default(); table.len()];
+        G::batch_normalize(&table, &mut affine_table);
+        Self {
+            table: affine_table,
+        }
+    }
+
+    pub fn mul(&self, scalar: &Scalar) -> G {
+        let mut acc = G::identity();
+        let digits = scalar
+            .to_bytes()
+            .into_iter()
+            .flat_map(|byte| [byte & 0x0f, byte >> 4]);
+        for (window, digit) in digits.enumerate() {
+            if digit != 0 {
+                acc += self.table[window * (WINDOW_SIZE - 1) + digit as usize - 1];
+            }
+        }
+        acc
+    }
+}
+
+/// Fixed-base table for the G1 generator, built on first use.
+pub fn g1_generator_table() -> &'static FixedBaseTable<G1Projective> {
+    static TABLE: Once<FixedBaseTable<G1Projective>> = Once::new();
+    TABLE.call_once(|| FixedBaseTable::new(G1Projective::generator()))
+}
+
+/// Fixed-base table for the G2 generator, built on first use.
+pub fn g2_generator_table() -> &'static FixedBaseTable<G2Projective> {
+    static TABLE: Once<FixedBaseTable<G2Projective>> = Once::new();
+    TABLE.call_once(|| FixedBaseTable::new(G2Projective::generator()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kzg_proof::compute_powers;
+
+    #[test]
+    fn test_fixed_base_mul() {
+        let mut scalars = compute_powers(&Scalar::from(0xdead_beef_cafe_babeu64), 16);
+        scalars.extend([Scalar::zero(), Scalar::one(), -Scalar::one()]);
+
+        for scalar in scalars {
+            assert_eq!(
+                g1_generator_table().mul(&scalar),
+                G1Projective::generator() * scalar
+            );
+            assert_eq!(
+                g2_generator_table().mul(&scalar),
+                G2Projective::generator() * scalar
+            );
+        }
+    }
+}
@@ -1,18 +1,24 @@
-use core::num::NonZeroUsize;
 use core::ops::Mul;
 
 use crate::enums::KzgError;
+use crate::fft::{fft, ifft};
+use crate::fixed_base::{g1_generator_table, g2_generator_table};
+use crate::msm::msm_variable_base;
 use crate::trusted_setup::KzgSettings;
+use crate::utils::bit_reversal_permutation;
 use crate::{
-    dtypes::*, pairings_verify, BYTES_PER_BLOB, BYTES_PER_COMMITMENT, BYTES_PER_FIELD_ELEMENT,
-    BYTES_PER_PROOF, CHALLENGE_INPUT_SIZE, DOMAIN_STR_LENGTH, FIAT_SHAMIR_PROTOCOL_DOMAIN, MODULUS,
-    NUM_FIELD_ELEMENTS_PER_BLOB, RANDOM_CHALLENGE_KZG_BATCH_DOMAIN,
+    dtypes::*, pairings_verify, BYTES_PER_BLOB, BYTES_PER_BLOB_RECORD, BYTES_PER_COMMITMENT,
+    BYTES_PER_FIELD_ELEMENT, BYTES_PER_PROOF, CHALLENGE_INPUT_SIZE, FIAT_SHAMIR_PROTOCOL_DOMAIN,
+    MAX_BLOBS_PER_BATCH, MODULUS, NUM_FIELD_ELEMENTS_PER_BLOB, RANDOM_CHALLENGE_KZG_BATCH_DOMAIN,
 };
 
 use alloc::{string::ToString, vec::Vec};
-use bls12_381::{G1Affine, G1Projective, G2Affine, G2Projective, Scalar};
+use bls12_381::{G1Affine, G1Projective, G2Affine, Scalar};
 use ff::derive::sbb;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 use sha2::{Digest, Sha256};
+use subtle::ConstantTimeEq;
 
 pub fn safe_g1_affine_from_bytes(bytes: &Bytes48) -> Result<G1Affine, KzgError> {
     let g1 = G1Affine::from_compressed(&(bytes.clone().into()));
@@ -24,6 +30,9 @@ pub fn safe_g1_affine_from_bytes(bytes: &Bytes48) -> Result<G1Affine, KzgError>
     Ok(g1.unwrap())
 }
 
+/// Parses a big-endian field element, rejecting any value of at least the modulus. Blob elements
+/// and evaluations are parsed this way; only hash outputs are reduced instead, with
+/// [`scalar_from_bytes_unchecked`].
 pub fn safe_scalar_affine_from_bytes(bytes: &Bytes32) -> Result<Scalar, KzgError> {
     let lendian: [u8; 32] = Into::<[u8; 32]>::into(bytes.clone())
         .iter()
@@ -42,35 +51,62 @@ pub fn safe_scalar_affine_from_bytes(bytes: &Bytes32) -> Result<Scalar, KzgError
     Ok(scalar.unwrap())
 }
 
-/// Return the Fiat-Shamir challenge required to verify `blob` and `commitment`.
-fn compute_challenge(blob: &Blob, commitment: &G1Affine) -> Result<Scalar, KzgError> {
-    let mut bytes = [0_u8; CHALLENGE_INPUT_SIZE];
-    let mut offset = 0_usize;
+/// Encodes a field element big-endian, as [`safe_scalar_affine_from_bytes`] parses it.
+pub(crate) fn scalar_to_bytes32(scalar: &Scalar) -> Bytes32 {
+    let mut bytes = scalar.to_bytes();
+    bytes.reverse();
+    Bytes32::from(bytes)
+}
+
+/// Starts the Fiat-Shamir transcript of [`compute_challenge`], which continues with the blob and
+/// then the commitment.
+pub(crate) fn challenge_transcript() -> Sha256 {
+    let mut hasher = Sha256::new();
     // Copy domain separator
-    bytes[offset..DOMAIN_STR_LENGTH].copy_from_slice(FIAT_SHAMIR_PROTOCOL_DOMAIN.as_bytes());
-    offset += DOMAIN_STR_LENGTH;
+    hasher.update(FIAT_SHAMIR_PROTOCOL_DOMAIN.as_bytes());
     // Copy polynomial degree (16-bytes, big-endian)
-    bytes[offset..offset + 8].copy_from_slice(&0_u64.to_be_bytes());
-    offset += 8;
-    bytes[offset..offset + 8].copy_from_slice(&(NUM_FIELD_ELEMENTS_PER_BLOB as u64).to_be_bytes());
-    offset += 8;
+    hasher.update(0_u64.to_be_bytes());
+    hasher.update((NUM_FIELD_ELEMENTS_PER_BLOB as u64).to_be_bytes());
+    hasher
+}
+
+/// Return the Fiat-Shamir challenge required to verify `blob` and `commitment`.
+pub fn compute_challenge(blob: &Blob, commitment: &G1Affine) -> Result<Scalar, KzgError> {
+    compute_challenge_with_digest(blob, commitment).map(|(_, challenge)| challenge)
+}
+
+/// [`compute_challenge`], also returning the SHA-256 digest that is reduced to the challenge, for
+/// callers that feed it into a transcript of their own.
+pub fn compute_challenge_with_digest(
+    blob: &Blob,
+    commitment: &G1Affine,
+) -> Result<(Bytes32, Scalar), KzgError> {
+    let evaluation = challenge_digest(blob.as_slice(), commitment);
+    Ok((
+        Bytes32::from(evaluation),
+        scalar_from_bytes_unchecked(evaluation),
+    ))
+}
+
+/// Hashes the raw bytes of a blob and its commitment into the digest behind the challenge.
+fn challenge_digest(blob: &[u8], commitment: &G1Affine) -> [u8; 32] {
+    let mut hasher = challenge_transcript();
     // Copy blob
-    bytes[offset..offset + BYTES_PER_BLOB].copy_from_slice(blob.as_slice());
-    offset += BYTES_PER_BLOB;
+    hasher.update(blob);
     // Copy commitment
-    bytes[offset..offset + BYTES_PER_COMMITMENT].copy_from_slice(&commitment.to_compressed());
-    offset += BYTES_PER_COMMITMENT;
-    /* Make sure we wrote the entire buffer */
-    if offset != CHALLENGE_INPUT_SIZE {
-        return Err(KzgError::InvalidBytesLength(format!(
-            "The challenge should be {} length, but was {}",
-            CHALLENGE_INPUT_SIZE, offset,
-        )));
-    }
-    let evaluation: [u8; 32] = Sha256::digest(bytes).into();
-    Ok(scalar_from_bytes_unchecked(evaluation))
+    hasher.update(commitment.to_compressed());
+    hasher.finalize().into()
+}
+
+/// Whether the big-endian `bytes` are below the modulus, so that
+/// [`safe_scalar_affine_from_bytes`] accepts them.
+pub fn is_canonical_field_element(bytes: &Bytes32) -> bool {
+    safe_scalar_affine_from_bytes(bytes).is_ok()
 }
 
+/// Reduces a big-endian 256-bit integer modulo the scalar field without rejecting anything. This
+/// is for hash outputs such as the Fiat-Shamir challenges, never for field elements read from
+/// inputs.
 pub fn scalar_from_bytes_unchecked(bytes: [u8; 32]) -> Scalar {
     scalar_from_u64_array_unchecked([
         u64::from_be_bytes(<[u8; 8]>::try_from(&bytes[0..8]).unwrap()),
@@ -80,19 +116,41 @@ pub fn scalar_from_bytes_unchecked(bytes: [u8; 32]) -> Scalar {
     ])
 }
 
+/// Reduces the big-endian limbs of a 256-bit integer modulo the scalar field, as c-kzg does when
+/// hashing to a field element. Since `2^256 < 3 * MODULUS`, at most two subtractions are needed.
 pub fn scalar_from_u64_array_unchecked(array: [u64; 4]) -> Scalar {
-    // Try to subtract the modulus
-    let (_, borrow) = sbb(array[0], MODULUS[0], 0);
-    let (_, borrow) = sbb(array[1], MODULUS[1], borrow);
-    let (_, borrow) = sbb(array[2], MODULUS[2], borrow);
-    let (_, _borrow) = sbb(array[3], MODULUS[3], borrow);
+    let mut limbs = [array[3], array[2], array[1], array[0]];
+
+    for _ in 0..2 {
+        // Try to subtract the modulus, keeping the difference if it did not underflow
+        let (d0, borrow) = sbb(limbs[0], MODULUS[0], 0);
+        let (d1, borrow) = sbb(limbs[1], MODULUS[1], borrow);
+        let (d2, borrow) = sbb(limbs[2], MODULUS[2], borrow);
+        let (d3, borrow) = sbb(limbs[3], MODULUS[3], borrow);
+        if borrow == 0 {
+            limbs = [d0, d1, d2, d3];
+        }
+    }
+
+    Scalar::from_raw(limbs)
+}
 
-    Scalar::from_raw([array[3], array[2], array[1], array[0]])
+/// Whether `z` is one of the settings' roots of unity. Evaluating there reads the polynomial's
+/// value directly, and proving there takes the special quotient formula of
+/// [`KzgProof::compute_kzg_proof`].
+pub fn is_in_evaluation_domain(z: &Scalar, kzg_settings: &KzgSettings) -> bool {
+    kzg_settings.roots_of_unity.contains(z)
 }
 
 /// Evaluates a polynomial in evaluation form at a given point
+///
+/// Outside the domain this uses the barycentric formula
+/// `p(x) = (x^n - 1) / n * sum_i p_i * w_i / (x - w_i)`, which assumes the roots of unity in the
+/// settings are all `n` of the `n`-th roots. If one were missing, evaluating at it would pass the
+/// domain check yet make `x^n - 1` zero and the result zero for every polynomial, so that case is
+/// rejected instead.
 pub fn evaluate_polynomial_in_evaluation_form(
-    polynomial: Vec<Scalar>,
+    polynomial: &[Scalar],
     x: Scalar,
     kzg_settings: &KzgSettings,
 ) -> Result<Scalar, KzgError> {
@@ -102,9 +160,9 @@ pub fn evaluate_polynomial_in_evaluation_form(
         ));
     }
 
+    let roots_of_unity = blob_roots_of_unity(kzg_settings)?;
     let mut inverses_in = vec![Scalar::default(); NUM_FIELD_ELEMENTS_PER_BLOB];
     let mut inverses = vec![Scalar::default(); NUM_FIELD_ELEMENTS_PER_BLOB];
-    let roots_of_unity = kzg_settings.roots_of_unity;
     for i in 0..NUM_FIELD_ELEMENTS_PER_BLOB {
         if x == roots_of_unity[i] {
             return Ok(polynomial[i]);
@@ -112,11 +170,14 @@ pub fn evaluate_polynomial_in_evaluation_form(
         inverses_in[i] = x - roots_of_unity[i];
     }
 
-    batch_inversion(
-        &mut inverses,
-        &inverses_in,
-        NonZeroUsize::new(NUM_FIELD_ELEMENTS_PER_BLOB).unwrap(),
-    )?;
+    batch_inversion(&mut inverses, &inverses_in)?;
+
+    let x_pow_n_minus_one = x.pow(&[NUM_FIELD_ELEMENTS_PER_BLOB as u64, 0, 0, 0]) - Scalar::one();
+    if x_pow_n_minus_one == Scalar::zero() {
+        return Err(KzgError::InvalidTrustedSetup(
+            "The point is a root of unity missing from the settings' domain".to_string(),
+        ));
+    }
 
     let mut out = Scalar::zero();
 
@@ -127,32 +188,75 @@ pub fn evaluate_polynomial_in_evaluation_form(
     out *= Scalar::from(NUM_FIELD_ELEMENTS_PER_BLOB as u64)
         .invert()
         .unwrap();
-    out *= x.pow(&[NUM_FIELD_ELEMENTS_PER_BLOB as u64, 0, 0, 0]) - Scalar::one();
+    out *= x_pow_n_minus_one;
 
     Ok(out)
 }
 
+/// Evaluates the polynomial with `coefficients`, lowest degree first, at `x` with Horner's method.
+pub fn evaluate_polynomial_monomial(coefficients: &[Scalar], x: Scalar) -> Scalar {
+    coefficients
+        .iter()
+        .rev()
+        .fold(Scalar::zero(), |acc, coefficient| acc * x + coefficient)
+}
+
+/// Interpolates the coefficients, lowest degree first, of the polynomial with `evaluations` over
+/// the `n`-th roots of unity, the first `n` of the settings' roots. The evaluations are in
+/// bit-reversed order, like blobs.
+pub fn lagrange_to_monomial(
+    evaluations: &[Scalar],
+    kzg_settings: &KzgSettings,
+) -> Result<Vec<Scalar>, KzgError> {
+    let n = evaluations.len();
+    if !n.is_power_of_two() || n > kzg_settings.roots_of_unity.len() {
+        return Err(KzgError::BadArgs(format!(
+            "Expected a power of two of at most {} evaluations, but got {}",
+            kzg_settings.roots_of_unity.len(),
+            n
+        )));
+    }
+
+    // Interpolate over the subdomain, in natural order
+    let mut coefficients = bit_reversal_permutation(evaluations)?;
+    let subdomain = bit_reversal_permutation(&kzg_settings.roots_of_unity[..n])?;
+    ifft(&mut coefficients, &subdomain)?;
+    Ok(coefficients)
+}
+
 /// Montgomery batch inversion in a finite field
 /// Given a list of elements \( x_1, x_2, \dots, x_n \) from a finite field \( F \), Montgomery batch inversion computes the inverses \( x_1^{-1}, x_2^{-1}, \dots, x_n^{-1} \) as follows:
 ///
 /// Let's consider three elements \( a \), \( b \), and \( c \) in a finite field \( F \). The steps are as follows:
 ///
 /// 1. **Product Accumulation**:
-///     \[
-///     P = a \times b \times c
-///     \]
+///    \[
+///    P = a \times b \times c
+///    \]
 ///
 /// 2. **Single Inversion**:
-///     \[
-///     P^{-1} = \text{inverse}(P)
-///     \]
+///    \[
+///    P^{-1} = \text{inverse}(P)
+///    \]
 ///
 /// 3. **Backward Substitution**:
 ///     - \( a^{-1} = P^{-1} \times (b \times c) \)
 ///     - \( b^{-1} = P^{-1} \times (a \times c) \)
 ///     - \( c^{-1} = P^{-1} \times (a \times b) \)
 ///
-fn batch_inversion(out: &mut [Scalar], a: &[Scalar], len: NonZeroUsize) -> Result<(), KzgError> {
+/// Writes the inverse of `a[i]` to `out[i]`. Fails if the slices differ in length, if `out`
+/// already holds the same values as `a`, or if any element is zero.
+pub fn batch_inversion(out: &mut [Scalar], a: &[Scalar]) -> Result<(), KzgError> {
+    if out.len() != a.len() {
+        return Err(KzgError::BadArgs(format!(
+            "Expected {} output elements, but got {}",
+            a.len(),
+            out.len()
+        )));
+    }
+    if a.is_empty() {
+        return Ok(());
+    }
     if a == out {
         return Err(KzgError::BadArgs(
             "Destination is the same as source".to_string(),
@@ -167,7 +271,7 @@ fn batch_inversion(out: &mut [Scalar], a: &[Scalar], len: NonZeroUsize) -> Resul
 
     let mut accumulator = Scalar::one();
 
-    for i in 0..len.into() {
+    for i in 0..a.len() {
         out[i] = accumulator;
         accumulator = accumulator.mul(&a[i]);
     }
@@ -188,7 +292,7 @@ fn batch_inversion(out: &mut [Scalar], a: &[Scalar], len: NonZeroUsize) -> Resul
     // \[
     // x_i^{-1} = P^{-1} \times \left(\prod_{j \neq i} x_j \right)
     // \]
-    for i in (0..len.into()).rev() {
+    for i in (0..a.len()).rev() {
         out[i] *= accumulator;
         accumulator *= a[i];
     }
@@ -196,28 +300,276 @@ fn batch_inversion(out: &mut [Scalar], a: &[Scalar], len: NonZeroUsize) -> Resul
     Ok(())
 }
 
-fn verify_kzg_proof_impl(
+/// The points fed into the final pairing check of a single proof verification. The proof is valid
+/// iff `e(p_minus_y, generator) == e(proof, x_minus_z)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PairingInputs {
+    pub p_minus_y: G1Affine,
+    pub generator: G2Affine,
+    pub proof: G1Affine,
+    pub x_minus_z: G2Affine,
+}
+
+impl PairingInputs {
+    /// Runs the pairing check on these inputs.
+    pub fn verify(&self) -> bool {
+        pairings_verify(self.p_minus_y, self.generator, self.proof, self.x_minus_z)
+    }
+}
+
+/// `commitment - [y]G1`, the G1 side of the pairing check.
+fn compute_p_minus_y(commitment: G1Affine, y: Scalar) -> G1Projective {
+    commitment - g1_generator_table().mul(&y)
+}
+
+fn compute_pairing_inputs(
     commitment: G1Affine,
     z: Scalar,
     y: Scalar,
     proof: G1Affine,
     kzg_settings: &KzgSettings,
-) -> Result<bool, KzgError> {
-    let x = G2Projective::generator() * z;
-    let x_minus_z = kzg_settings.g2_points[1] - x;
+) -> Result<PairingInputs, KzgError> {
+    let x = g2_generator_table().mul(&z);
+    let x_minus_z = kzg_settings.g2_secret_generator()? - x;
 
-    let y = G1Projective::generator() * y;
-    let p_minus_y = commitment - y;
+    Ok(PairingInputs {
+        p_minus_y: compute_p_minus_y(commitment, y).into(),
+        generator: G2Affine::generator(),
+        proof,
+        x_minus_z: x_minus_z.into(),
+    })
+}
 
+pub(crate) fn verify_kzg_proof_impl(
+    commitment: G1Affine,
+    z: Scalar,
+    y: Scalar,
+    proof: G1Affine,
+    kzg_settings: &KzgSettings,
+) -> Result<bool, KzgError> {
     // Verify: P - y = Q * (X - z)
+    Ok(compute_pairing_inputs(commitment, z, y, proof, kzg_settings)?.verify())
+}
+
+/// The pairing check of batch verification, `e(proof_lincomb, [s]G2) == e(rhs_g1, G2)`.
+fn verify_batch_lincombs(
+    proof_lincomb: G1Projective,
+    rhs_g1: G1Projective,
+    kzg_settings: &KzgSettings,
+) -> Result<bool, KzgError> {
+    // Both sides were computed projective; normalize them with one inversion
+    let mut lhs_rhs = [G1Affine::identity(); 2];
+    G1Projective::batch_normalize(&[proof_lincomb, rhs_g1], &mut lhs_rhs);
+    let [proof_lincomb, rhs_g1] = lhs_rhs;
+
+    // Verify the pairing equation
     Ok(pairings_verify(
-        p_minus_y.into(),
-        G2Projective::generator().into(),
-        proof,
-        x_minus_z.into(),
+        proof_lincomb,
+        *kzg_settings.g2_secret_generator()?,
+        rhs_g1,
+        G2Affine::generator(),
     ))
 }
 
+/// Returns the root of unity at which the blob element at `index` is the polynomial's value.
+fn domain_point(index: usize, kzg_settings: &KzgSettings) -> Result<Scalar, KzgError> {
+    if index >= NUM_FIELD_ELEMENTS_PER_BLOB {
+        return Err(KzgError::BadArgs(format!(
+            "The index {} is out of range, the blob has {} elements",
+            index, NUM_FIELD_ELEMENTS_PER_BLOB
+        )));
+    }
+    kzg_settings.root_of_unity(index).copied()
+}
+
+/// Returns the roots of unity of the blob domain, checking that the settings have all of them.
+fn blob_roots_of_unity(kzg_settings: &KzgSettings) -> Result<&[Scalar], KzgError> {
+    if kzg_settings.roots_of_unity.len() != NUM_FIELD_ELEMENTS_PER_BLOB {
+        return Err(KzgError::InvalidTrustedSetup(format!(
+            "Expected {} roots of unity, but the settings have {}",
+            NUM_FIELD_ELEMENTS_PER_BLOB,
+            kzg_settings.roots_of_unity.len()
+        )));
+    }
+    Ok(&kzg_settings.roots_of_unity)
+}
+
+/// Computes the MSM of the Lagrange-form G1 points against a polynomial in evaluation form.
+fn commit_to_polynomial(
+    polynomial: &[Scalar],
+    kzg_settings: &KzgSettings,
+) -> Result<G1Affine, KzgError> {
+    if kzg_settings.g1_points.len() != NUM_FIELD_ELEMENTS_PER_BLOB {
+        return Err(KzgError::InvalidTrustedSetup(format!(
+            "Expected {} G1 points, but the settings have {}",
+            NUM_FIELD_ELEMENTS_PER_BLOB,
+            kzg_settings.g1_points.len()
+        )));
+    }
+
+    let g1_points = kzg_settings
+        .g1_points
+        .iter()
+        .map(G1Projective::from)
+        .collect::<Vec<_>>();
+
+    Ok(msm_variable_base(&g1_points, polynomial).into())
+}
+
+/// Returns the coefficients, lowest degree first, of the vanishing polynomial `prod(x - z_i)`.
+fn vanishing_polynomial(points: &[Scalar]) -> Vec<Scalar> {
+    let mut coefficients = vec![Scalar::one()];
+    for z in points {
+        // Multiply by (x - z)
+        coefficients.push(Scalar::zero());
+        for i in (0..coefficients.len()).rev() {
+            let lower = if i > 0 {
+                coefficients[i - 1]
+            } else {
+                Scalar::zero()
+            };
+            coefficients[i] = lower - coefficients[i] * z;
+        }
+    }
+    coefficients
+}
+
+/// Commits to the vanishing polynomial `prod(x - z_i)` of `points`, which vanishes exactly on
+/// them. Its degree, the number of points, must be below [`NUM_FIELD_ELEMENTS_PER_BLOB`].
+pub fn compute_vanishing_poly_commitment(
+    points: &[Scalar],
+    kzg_settings: &KzgSettings,
+) -> Result<G1Affine, KzgError> {
+    if points.len() >= NUM_FIELD_ELEMENTS_PER_BLOB {
+        return Err(KzgError::BadArgs(format!(
+            "At most {} points are supported, but got {}",
+            NUM_FIELD_ELEMENTS_PER_BLOB - 1,
+            points.len()
+        )));
+    }
+    blob_roots_of_unity(kzg_settings)?;
+
+    // Evaluate over the whole domain, then commit in the bit-reversed order of blobs
+    let mut polynomial = vanishing_polynomial(points);
+    polynomial.resize(NUM_FIELD_ELEMENTS_PER_BLOB, Scalar::zero());
+    let domain = bit_reversal_permutation(&kzg_settings.roots_of_unity)?;
+    fft(&mut polynomial, &domain)?;
+    commit_to_polynomial(&bit_reversal_permutation(&polynomial)?, kzg_settings)
+}
+
+/// Computes the quotient `q(x) = (p(x) - y) / (x - z)` in evaluation form and commits to it.
+///
+/// When `z` is the root of unity `w_m`, `q(w_m)` is a 0/0 division and is instead computed as
+/// `sum_{i != m} (p_i - y) * w_i / (z * (z - w_i))`.
+fn compute_kzg_proof_impl(
+    polynomial: &[Scalar],
+    z: Scalar,
+    kzg_settings: &KzgSettings,
+) -> Result<(G1Affine, Scalar), KzgError> {
+    let roots_of_unity = blob_roots_of_unity(kzg_settings)?;
+    let y = evaluate_polynomial_in_evaluation_form(polynomial, z, kzg_settings)?;
+
+    let mut quotient = vec![Scalar::zero(); NUM_FIELD_ELEMENTS_PER_BLOB];
+    let mut inverses_in = vec![Scalar::one(); NUM_FIELD_ELEMENTS_PER_BLOB];
+    let mut inverses = vec![Scalar::zero(); NUM_FIELD_ELEMENTS_PER_BLOB];
+    let mut m = None;
+
+    for i in 0..NUM_FIELD_ELEMENTS_PER_BLOB {
+        if z == roots_of_unity[i] {
+            // The quotient at this index is computed separately below
+            m = Some(i);
+            continue;
+        }
+        quotient[i] = polynomial[i] - y;
+        inverses_in[i] = roots_of_unity[i] - z;
+    }
+
+    batch_inversion(&mut inverses, &inverses_in)?;
+
+    for i in 0..NUM_FIELD_ELEMENTS_PER_BLOB {
+        quotient[i] *= inverses[i];
+    }
+
+    if let Some(m) = m {
+        for i in 0..NUM_FIELD_ELEMENTS_PER_BLOB {
+            if i == m {
+                continue;
+            }
+            inverses_in[i] = (z - roots_of_unity[i]) * z;
+        }
+
+        batch_inversion(&mut inverses, &inverses_in)?;
+
+        quotient[m] = Scalar::zero();
+        for i in 0..NUM_FIELD_ELEMENTS_PER_BLOB {
+            if i == m {
+                continue;
+            }
+            quotient[m] += (polynomial[i] - y) * roots_of_unity[i] * inverses[i];
+        }
+    }
+
+    Ok((commit_to_polynomial(&quotient, kzg_settings)?, y))
+}
+
+/// Low-degree extends a polynomial given by its evaluations over the `m`-th roots of unity, the
+/// first `m` of the settings' roots, to its evaluations over the whole blob domain. Both are in
+/// bit-reversed order, like blobs.
+fn extend_to_blob_domain(
+    evaluations: &[Bytes32],
+    domain_size: usize,
+    kzg_settings: &KzgSettings,
+) -> Result<Vec<Scalar>, KzgError> {
+    if !domain_size.is_power_of_two() || domain_size > NUM_FIELD_ELEMENTS_PER_BLOB {
+        return Err(KzgError::BadArgs(format!(
+            "The domain size must be a power of two of at most {}, but was {}",
+            NUM_FIELD_ELEMENTS_PER_BLOB, domain_size
+        )));
+    }
+    if evaluations.len() != domain_size {
+        return Err(KzgError::BadArgs(format!(
+            "Expected {} evaluations, but got {}",
+            domain_size,
+            evaluations.len()
+        )));
+    }
+    if kzg_settings.roots_of_unity.len() != NUM_FIELD_ELEMENTS_PER_BLOB {
+        return Err(KzgError::InvalidTrustedSetup(format!(
+            "Expected {} roots of unity, but the settings have {}",
+            NUM_FIELD_ELEMENTS_PER_BLOB,
+            kzg_settings.roots_of_unity.len()
+        )));
+    }
+
+    let evaluations = evaluations
+        .iter()
+        .map(safe_scalar_affine_from_bytes)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Evaluate the interpolated polynomial over the whole domain
+    let mut coefficients = lagrange_to_monomial(&evaluations, kzg_settings)?;
+    coefficients.resize(NUM_FIELD_ELEMENTS_PER_BLOB, Scalar::zero());
+    let domain = bit_reversal_permutation(&kzg_settings.roots_of_unity)?;
+    fft(&mut coefficients, &domain)?;
+
+    bit_reversal_permutation(&coefficients)
+}
+
+fn verify_blob_kzg_proof_impl(
+    blob: &Blob,
+    commitment_bytes: &Bytes48,
+    proof_bytes: &Bytes48,
+    kzg_settings: &KzgSettings,
+) -> Result<bool, KzgError> {
+    KzgProof::verify_blob_kzg_proof_with_challenge(
+        blob,
+        commitment_bytes,
+        proof_bytes,
+        kzg_settings,
+    )
+    .map(|(valid, _, _)| valid)
+}
+
 fn validate_batched_input(commitment: &[G1Affine], proofs: &[G1Affine]) -> Result<(), KzgError> {
     // Check if any commitment is invalid (not on curve or identity)
     let invalid_commitment = commitment.iter().any(|commitment| {
@@ -241,105 +593,245 @@ fn validate_batched_input(commitment: &[G1Affine], proofs: &[G1Affine]) -> Resul
     Ok(()) // Return Ok if all commitments and proofs are valid
 }
 
+/// Computes the Fiat-Shamir challenge for the blob and its commitment, and the evaluation of the
+/// blob's polynomial at it.
+fn compute_challenge_and_evaluate_polynomial(
+    blob: &Blob,
+    commitment: &G1Affine,
+    kzg_settings: &KzgSettings,
+) -> Result<(Scalar, Scalar), KzgError> {
+    compute_challenge_and_evaluate_blob_bytes(blob.as_slice(), commitment, kzg_settings)
+}
+
+/// [`compute_challenge_and_evaluate_polynomial`] over the raw bytes of a blob, for callers that
+/// hold it in a larger buffer and would otherwise have to copy it into a [`Blob`].
+fn compute_challenge_and_evaluate_blob_bytes(
+    blob: &[u8],
+    commitment: &G1Affine,
+    kzg_settings: &KzgSettings,
+) -> Result<(Scalar, Scalar), KzgError> {
+    // Convert the blob to its polynomial representation
+    let polynomial = blob
+        .chunks(BYTES_PER_FIELD_ELEMENT)
+        .map(|slice| {
+            Bytes32::from_slice(slice).and_then(|bytes| safe_scalar_affine_from_bytes(&bytes))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    // Compute the Fiat-Shamir challenge for the blob and its commitment
+    let evaluation_challenge = scalar_from_bytes_unchecked(challenge_digest(blob, commitment));
+    // Evaluate the polynomial at the computed challenge
+    let y =
+        evaluate_polynomial_in_evaluation_form(&polynomial, evaluation_challenge, kzg_settings)?;
+
+    Ok((evaluation_challenge, y))
+}
+
+/// Runs [`compute_challenge_and_evaluate_polynomial`] for every blob, across threads with the
+/// `parallel` feature.
 fn compute_challenges_and_evaluate_polynomial(
-    blobs: Vec<Blob>,
+    blobs: &[Blob],
     commitment: &[G1Affine],
     kzg_settings: &KzgSettings,
 ) -> Result<(Vec<Scalar>, Vec<Scalar>), KzgError> {
-    // Initialize vectors to store evaluation challenges and polynomial evaluations
-    let mut evaluation_challenges = Vec::with_capacity(blobs.len());
-    let mut ys = Vec::with_capacity(blobs.len());
-
-    // Iterate over each blob to compute its polynomial evaluation
-    for i in 0..blobs.len() {
-        // Convert the blob to its polynomial representation
-        let polynomial = blobs[i].as_polynomial()?;
-        // Compute the Fiat-Shamir challenge for the current blob and its commitment
-        let evaluation_challenge = compute_challenge(&blobs[i], &commitment[i])?;
-        // Evaluate the polynomial at the computed challenge
-        let y =
-            evaluate_polynomial_in_evaluation_form(polynomial, evaluation_challenge, kzg_settings)?;
-
-        // Store the evaluation challenge and the polynomial evaluation
-        evaluation_challenges.push(evaluation_challenge);
-        ys.push(y);
-    }
-
-    // Return the vectors of evaluation challenges and polynomial evaluations
-    Ok((evaluation_challenges, ys))
+    #[cfg(feature = "parallel")]
+    let evaluations = blobs
+        .par_iter()
+        .zip(commitment)
+        .map(|(blob, commitment)| {
+            compute_challenge_and_evaluate_polynomial(blob, commitment, kzg_settings)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    #[cfg(not(feature = "parallel"))]
+    let evaluations = blobs
+        .iter()
+        .zip(commitment)
+        .map(|(blob, commitment)| {
+            compute_challenge_and_evaluate_polynomial(blob, commitment, kzg_settings)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Split into the evaluation challenges and the polynomial evaluations
+    Ok(evaluations.into_iter().unzip())
 }
 
+/// Returns `[1, base, base^2, ..., base^(num_powers - 1)]`: `powers[0]` is always one, and no
+/// powers is an empty vector.
 pub fn compute_powers(base: &Scalar, num_powers: usize) -> Vec<Scalar> {
     let mut powers = vec![Scalar::default(); num_powers];
-    if num_powers == 0 {
-        return powers;
-    }
-    powers[0] = Scalar::one();
-    for i in 1..num_powers {
-        powers[i] = powers[i - 1].mul(base);
-    }
+    compute_powers_into(base, &mut powers);
     powers
 }
 
-fn compute_r_powers(
+/// Writes the powers of [`compute_powers`] into `out`, as many as it holds, so hot loops can reuse
+/// one buffer.
+pub fn compute_powers_into(base: &Scalar, out: &mut [Scalar]) {
+    let mut power = Scalar::one();
+    for slot in out.iter_mut() {
+        *slot = power;
+        power *= base;
+    }
+}
+
+/// The batch transcript header: the domain separator, the degree and `n` as big-endian `u64`s.
+fn batch_transcript_header(n: usize) -> [u8; 32] {
+    let mut bytes = [0; 32];
+    // Copy domain separator
+    bytes[..16].copy_from_slice(RANDOM_CHALLENGE_KZG_BATCH_DOMAIN.as_bytes());
+    bytes[16..24].copy_from_slice(&(NUM_FIELD_ELEMENTS_PER_BLOB as u64).to_be_bytes());
+    bytes[24..32].copy_from_slice(&(n as u64).to_be_bytes());
+    bytes
+}
+
+/// Computes the powers of the random challenge `r` that batch verification uses to combine the
+/// proofs, with the same transcript as c-kzg: the domain separator, the degree and `n` as 8-byte
+/// big-endian integers, then each compressed commitment, big-endian `z`, big-endian `y` and
+/// compressed proof.
+pub fn compute_r_powers(
     commitment: &[G1Affine],
     zs: &[Scalar],
     ys: &[Scalar],
     proofs: &[G1Affine],
 ) -> Result<Vec<Scalar>, KzgError> {
-    let n = commitment.len();
-    let input_size =
-        32 + n * (BYTES_PER_COMMITMENT + 2 * BYTES_PER_FIELD_ELEMENT + BYTES_PER_PROOF);
-
-    let mut bytes: Vec<u8> = vec![0; input_size];
-
-    // Copy domain separator
-    bytes[..16].copy_from_slice(RANDOM_CHALLENGE_KZG_BATCH_DOMAIN.as_bytes());
-
-    bytes[16..24].copy_from_slice(&(NUM_FIELD_ELEMENTS_PER_BLOB as u64).to_be_bytes());
+    let r = compute_r(commitment, zs, ys, proofs)?;
+    Ok(compute_powers(&r, commitment.len()))
+}
 
-    let mut n_bytes = n.to_be_bytes().to_vec();
-    n_bytes.resize(8, 0);
-    bytes[24..32].copy_from_slice(&n_bytes);
+/// The challenge `r` of [`compute_r_powers`], hashing the transcript as it is written.
+fn compute_r(
+    commitment: &[G1Affine],
+    zs: &[Scalar],
+    ys: &[Scalar],
+    proofs: &[G1Affine],
+) -> Result<Scalar, KzgError> {
+    let n = commitment.len();
+    if zs.len() != n || ys.len() != n || proofs.len() != n {
+        return Err(KzgError::BadArgs(
+            "The commitments, zs, ys and proofs must have the same length".to_string(),
+        ));
+    }
 
-    let mut offset = 32;
+    let mut hasher = Sha256::new();
+    hasher.update(batch_transcript_header(n));
 
     for i in 0..n {
         // Copy commitment
-        let v = commitment[i].to_compressed();
-        bytes[offset..(v.len() + offset)].copy_from_slice(&v[..]);
-        offset += BYTES_PER_COMMITMENT;
+        hasher.update(commitment[i].to_compressed());
 
         // Copy evaluation challenge
-        let v = zs[i].to_bytes();
-        bytes[offset..(v.len() + offset)].copy_from_slice(&v[..]);
-        offset += BYTES_PER_FIELD_ELEMENT;
+        hasher.update(scalar_to_bytes32(&zs[i]));
 
         // Copy polynomial's evaluation value
-        let v = ys[i].to_bytes();
-        bytes[offset..(v.len() + offset)].copy_from_slice(&v[..]);
-        offset += BYTES_PER_FIELD_ELEMENT;
+        hasher.update(scalar_to_bytes32(&ys[i]));
 
         // Copy proof
-        let v = proofs[i].to_compressed();
-        bytes[offset..(v.len() + offset)].copy_from_slice(&v[..]);
-        offset += BYTES_PER_PROOF;
+        hasher.update(proofs[i].to_compressed());
     }
 
-    // Make sure we wrote the entire buffer
-    if offset != input_size {
-        return Err(KzgError::InvalidBytesLength(
-            "Error while copying commitments".to_string(),
-        ));
+    // Now let's create the challenge!
+    Ok(scalar_from_bytes_unchecked(hasher.finalize().into()))
+}
+
+/// The approximate work done by [`KzgProof::verify_blob_kzg_proof_batch_ref`], counted from the
+/// structure of the algorithm rather than measured. See [`estimate_batch_cost`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BatchCostEstimate {
+    /// Compressed commitments and proofs decoded, each with a subgroup check.
+    pub point_decompressions: usize,
+    /// Bytes hashed for the Fiat-Shamir challenges and the batch challenge.
+    pub sha256_bytes: usize,
+    /// Scalar field multiplications, dominated by the barycentric evaluation of each blob.
+    pub field_multiplications: usize,
+    /// Scalar field inversions, one per blob thanks to batch inversion.
+    pub field_inversions: usize,
+    /// Fixed-base scalar multiplications by the G1 or G2 generator.
+    pub fixed_base_multiplications: usize,
+    /// The sizes of the G1 multi-scalar multiplications.
+    pub msm_sizes: Vec<usize>,
+    /// Pairings, all computed in one multi-Miller loop with a final exponentiation.
+    pub pairings: usize,
+}
+
+/// Estimates the cost of verifying a batch of `n` blob proofs, to size worker pools and batch
+/// limits without benchmarking. A single blob takes the path of
+/// [`KzgProof::verify_blob_kzg_proof`], with no MSMs.
+pub fn estimate_batch_cost(n: usize) -> BatchCostEstimate {
+    if n == 0 {
+        return BatchCostEstimate::default();
     }
 
-    // Now let's create the challenge!
-    let evaluation: [u8; 32] = Sha256::digest(bytes).into();
-    let r = scalar_from_bytes_unchecked(evaluation);
+    // Batch inversion of the n denominators takes 3n multiplications, the sum 2n more, and x^n
+    // one squaring per bit of n
+    let evaluation_multiplications =
+        5 * NUM_FIELD_ELEMENTS_PER_BLOB + NUM_FIELD_ELEMENTS_PER_BLOB.trailing_zeros() as usize + 2;
+    let blob_costs = BatchCostEstimate {
+        point_decompressions: 2 * n,
+        sha256_bytes: n * CHALLENGE_INPUT_SIZE,
+        field_multiplications: n * evaluation_multiplications,
+        field_inversions: n,
+        ..Default::default()
+    };
+    if n == 1 {
+        // [y]G1 and [z]G2
+        return BatchCostEstimate {
+            fixed_base_multiplications: 2,
+            pairings: 2,
+            ..blob_costs
+        };
+    }
+
+    BatchCostEstimate {
+        // The transcript for r, which is then raised to n powers and multiplied into each z
+        sha256_bytes: blob_costs.sha256_bytes
+            + 32
+            + n * (BYTES_PER_COMMITMENT + 2 * BYTES_PER_FIELD_ELEMENT + BYTES_PER_PROOF),
+        field_multiplications: blob_costs.field_multiplications + 2 * n,
+        // [y_i]G1 for every blob
+        fixed_base_multiplications: n,
+        // The proofs weighted by r^i and by r^i * z_i, and the commitments minus [y_i]G1
+        msm_sizes: vec![n; 3],
+        pairings: 2,
+        ..blob_costs
+    }
+}
+
+/// A caller's record of commitments to reject without verifying, such as a Bloom filter or an LRU
+/// cache of commitments whose proofs already failed. The filtered verify functions check it
+/// before decompressing anything.
+///
+/// Closures of type `Fn(&Bytes48) -> bool` are filters.
+pub trait CommitmentFilter {
+    fn is_rejected(&self, commitment: &Bytes48) -> bool;
+}
+
+impl<F: Fn(&Bytes48) -> bool> CommitmentFilter for F {
+    fn is_rejected(&self, commitment: &Bytes48) -> bool {
+        self(commitment)
+    }
+}
 
-    Ok(compute_powers(&r, n))
+/// Opens a blob at one of its elements: `value` is the element at the index, and `proof` the
+/// KZG proof of the polynomial's evaluation at the matching root of unity.
+#[derive(Debug, Clone)]
+pub struct InclusionProof {
+    pub value: Bytes32,
+    pub proof: Bytes48,
 }
 
+/// Proof generation and verification.
+///
+/// The `verify_*` functions that return `Result<bool, KzgError>` give `Ok(true)` for a valid
+/// proof, `Ok(false)` for well-formed inputs whose proof does not verify, and `Err` only for
+/// malformed inputs, such as a point that does not decompress or a field element that is not
+/// canonical. The exceptions are [`KzgProof::verify_blob_kzg_proof_filtered`] and
+/// [`KzgProof::verify_blob_kzg_proof_batch_filtered`], which return `Ok(false)` for any
+/// commitment the filter rejects, even one that does not decompress.
+///
+/// The other `verify_*` functions return something else, described in their own docs: a
+/// `Vec<bool>` per proof from [`KzgProof::verify_blob_kzg_proof_batch_detailed`] and
+/// [`KzgProof::verify_blob_kzg_proof_batch_permuted`], the result with its challenge from
+/// [`KzgProof::verify_blob_kzg_proof_with_challenge`], the [`PairingInputs`] from
+/// [`KzgProof::verify_kzg_proof_pairing_inputs`], and `Result<(), KzgError>` from
+/// [`KzgProof::verify_blob_kzg_proof_batch_strict`].
 pub struct KzgProof {}
 
 impl KzgProof {
@@ -375,18 +867,87 @@ impl KzgProof {
             }
         };
 
-        let g2_x = G2Affine::generator() * z;
-        let x_minus_z = kzg_settings.g2_points[1] - g2_x;
+        verify_kzg_proof_impl(commitment, z, y, proof, kzg_settings)
+    }
+
+    /// Like [`KzgProof::verify_kzg_proof`], but first checks in constant time that the commitment
+    /// is `expected_commitment`, so a valid proof for a substituted commitment returns `false`.
+    pub fn verify_kzg_proof_checked(
+        expected_commitment_bytes: &Bytes48,
+        commitment_bytes: &Bytes48,
+        z_bytes: &Bytes32,
+        y_bytes: &Bytes32,
+        proof_bytes: &Bytes48,
+        kzg_settings: &KzgSettings,
+    ) -> Result<bool, KzgError> {
+        let matches_expected = expected_commitment_bytes
+            .as_slice()
+            .ct_eq(commitment_bytes.as_slice());
+        if !bool::from(matches_expected) {
+            return Ok(false);
+        }
+
+        Self::verify_kzg_proof(
+            commitment_bytes,
+            z_bytes,
+            y_bytes,
+            proof_bytes,
+            kzg_settings,
+        )
+    }
+
+    /// Like [`KzgProof::verify_kzg_proof`], but first evaluates `blob` at `z` and returns `false`
+    /// unless that is `y`, so a `y` that is inconsistent with the blob fails even when its proof
+    /// verifies.
+    pub fn verify_kzg_proof_with_blob(
+        blob: &Blob,
+        commitment_bytes: &Bytes48,
+        z_bytes: &Bytes32,
+        y_bytes: &Bytes32,
+        proof_bytes: &Bytes48,
+        kzg_settings: &KzgSettings,
+    ) -> Result<bool, KzgError> {
+        // Decode every input first, so malformed ones are errors rather than `Ok(false)`
+        let commitment = safe_g1_affine_from_bytes(commitment_bytes)?;
+        let z = safe_scalar_affine_from_bytes(z_bytes)?;
+        let y = safe_scalar_affine_from_bytes(y_bytes)?;
+        let proof = safe_g1_affine_from_bytes(proof_bytes)?;
+        let polynomial = blob.as_polynomial()?;
+        if evaluate_polynomial_in_evaluation_form(&polynomial, z, kzg_settings)? != y {
+            return Ok(false);
+        }
+
+        verify_kzg_proof_impl(commitment, z, y, proof, kzg_settings)
+    }
 
-        let g1_y = G1Affine::generator() * y;
-        let p_minus_y = commitment - g1_y;
+    /// Decodes the inputs of [`KzgProof::verify_kzg_proof`] and returns the points it would
+    /// pass to the pairing check, without computing the pairing. Lets external tooling re-derive
+    /// the verification equation with an independent implementation.
+    pub fn verify_kzg_proof_pairing_inputs(
+        commitment_bytes: &Bytes48,
+        z_bytes: &Bytes32,
+        y_bytes: &Bytes32,
+        proof_bytes: &Bytes48,
+        kzg_settings: &KzgSettings,
+    ) -> Result<PairingInputs, KzgError> {
+        let z = safe_scalar_affine_from_bytes(z_bytes)?;
+        let y = safe_scalar_affine_from_bytes(y_bytes)?;
+        let commitment = safe_g1_affine_from_bytes(commitment_bytes)?;
+        let proof = safe_g1_affine_from_bytes(proof_bytes)?;
 
-        Ok(pairings_verify(
-            p_minus_y.into(),
-            G2Affine::generator(),
-            proof,
-            x_minus_z.into(),
-        ))
+        compute_pairing_inputs(commitment, z, y, proof, kzg_settings)
+    }
+
+    /// Returns `commitment - [y]G1`, the `p_minus_y` of [`PairingInputs`], for comparing against
+    /// an expected value when debugging a proof that fails. It does not depend on the setup.
+    pub fn reconstruct_p_minus_y(
+        commitment_bytes: &Bytes48,
+        y_bytes: &Bytes32,
+    ) -> Result<G1Affine, KzgError> {
+        let y = safe_scalar_affine_from_bytes(y_bytes)?;
+        let commitment = safe_g1_affine_from_bytes(commitment_bytes)?;
+
+        Ok(compute_p_minus_y(commitment, y).into())
     }
 
     pub fn verify_kzg_proof_batch(
@@ -398,10 +959,6 @@ impl KzgProof {
     ) -> Result<bool, KzgError> {
         let n = commitments.len();
 
-        // Initialize vectors to store intermediate values
-        let mut c_minus_y: Vec<G1Projective> = Vec::with_capacity(n);
-        let mut r_times_z: Vec<Scalar> = Vec::with_capacity(n);
-
         // Compute r powers
         let r_powers = compute_r_powers(commitments, zs, ys, proofs)?;
 
@@ -409,76 +966,378 @@ impl KzgProof {
         let proofs = proofs.iter().map(Into::into).collect::<Vec<_>>();
 
         // Compute proof linear combination
-        let proof_lincomb = G1Projective::msm_variable_base(&proofs, &r_powers);
+        let proof_lincomb = msm_variable_base(&proofs, &r_powers);
 
         // Compute c_minus_y and r_times_z
-        for i in 0..n {
-            let ys_encrypted = G1Affine::generator() * ys[i];
-            c_minus_y.push(commitments[i] - ys_encrypted);
-            r_times_z.push(r_powers[i] * zs[i]);
-        }
+        let c_minus_y_and_r_times_z = |i: usize| {
+            let ys_encrypted = g1_generator_table().mul(&ys[i]);
+            (commitments[i] - ys_encrypted, r_powers[i] * zs[i])
+        };
+        #[cfg(feature = "parallel")]
+        let (c_minus_y, r_times_z): (Vec<G1Projective>, Vec<Scalar>) =
+            (0..n).into_par_iter().map(c_minus_y_and_r_times_z).unzip();
+        #[cfg(not(feature = "parallel"))]
+        let (c_minus_y, r_times_z): (Vec<G1Projective>, Vec<Scalar>) =
+            (0..n).map(c_minus_y_and_r_times_z).unzip();
 
         // Compute proof_z_lincomb and c_minus_y_lincomb
-        let proof_z_lincomb = G1Projective::msm_variable_base(&proofs, &r_times_z);
-        let c_minus_y_lincomb = G1Projective::msm_variable_base(&c_minus_y, &r_powers);
+        let proof_z_lincomb = msm_variable_base(&proofs, &r_times_z);
+        let c_minus_y_lincomb = msm_variable_base(&c_minus_y, &r_powers);
 
         // Compute rhs_g1
         let rhs_g1 = c_minus_y_lincomb + proof_z_lincomb;
 
-        // Verify the pairing equation
-        let result = pairings_verify(
-            proof_lincomb.into(),
-            kzg_settings.g2_points[1],
-            rhs_g1.into(),
-            G2Affine::generator(),
-        );
-
-        Ok(result)
+        verify_batch_lincombs(proof_lincomb, rhs_g1, kzg_settings)
     }
 
-    pub fn verify_blob_kzg_proof(
-        blob: Blob,
-        commitment_bytes: &Bytes48,
-        proof_bytes: &Bytes48,
+    /// Like [`KzgProof::verify_kzg_proof_batch`], but adds each input into the two linear
+    /// combinations as it goes instead of collecting points and powers of `r` for MSMs. Besides
+    /// the inputs, it only holds `r`, the current power and two projective accumulators, whatever
+    /// the batch size, at the cost of three scalar multiplications per input, which suits
+    /// embedded verifiers with small batches.
+    pub fn verify_kzg_proof_batch_low_memory(
+        commitments: &[G1Affine],
+        zs: &[Scalar],
+        ys: &[Scalar],
+        proofs: &[G1Affine],
         kzg_settings: &KzgSettings,
     ) -> Result<bool, KzgError> {
-        // Convert commitment bytes to G1Affine
-        let commitment = safe_g1_affine_from_bytes(commitment_bytes)?;
+        let r = compute_r(commitments, zs, ys, proofs)?;
+
+        let mut r_power = Scalar::one();
+        let mut proof_lincomb = G1Projective::identity();
+        let mut rhs_g1 = G1Projective::identity();
+        for i in 0..commitments.len() {
+            let c_minus_y = compute_p_minus_y(commitments[i], ys[i]);
+            proof_lincomb += proofs[i] * r_power;
+            rhs_g1 += c_minus_y * r_power + proofs[i] * (r_power * zs[i]);
+            r_power *= r;
+        }
 
-        // Convert blob to polynomial
-        let polynomial = blob.as_polynomial()?;
+        verify_batch_lincombs(proof_lincomb, rhs_g1, kzg_settings)
+    }
 
-        // Convert proof bytes to G1Affine
-        let proof = safe_g1_affine_from_bytes(proof_bytes)?;
+    /// [`KzgProof::verify_kzg_proof_batch`] on encoded inputs: parses and checks every
+    /// commitment, point, evaluation and proof first.
+    pub fn verify_kzg_proof_batch_bytes(
+        commitments_bytes: &[Bytes48],
+        zs_bytes: &[Bytes32],
+        ys_bytes: &[Bytes32],
+        proofs_bytes: &[Bytes48],
+        kzg_settings: &KzgSettings,
+    ) -> Result<bool, KzgError> {
+        let n = commitments_bytes.len();
+        if zs_bytes.len() != n || ys_bytes.len() != n || proofs_bytes.len() != n {
+            return Err(KzgError::BadArgs(
+                "The commitments, zs, ys and proofs must have the same length".to_string(),
+            ));
+        }
 
-        // Compute the evaluation challenge for the blob and commitment
-        let evaluation_challenge = compute_challenge(&blob, &commitment)?;
+        let commitments = commitments_bytes
+            .iter()
+            .map(safe_g1_affine_from_bytes)
+            .collect::<Result<Vec<_>, _>>()?;
+        let zs = zs_bytes
+            .iter()
+            .map(safe_scalar_affine_from_bytes)
+            .collect::<Result<Vec<_>, _>>()?;
+        let ys = ys_bytes
+            .iter()
+            .map(safe_scalar_affine_from_bytes)
+            .collect::<Result<Vec<_>, _>>()?;
+        let proofs = proofs_bytes
+            .iter()
+            .map(safe_g1_affine_from_bytes)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Self::verify_kzg_proof_batch(&commitments, &zs, &ys, &proofs, kzg_settings)
+    }
+
+    /// Commits to the blob as a polynomial in evaluation form, via an MSM of the Lagrange-form
+    /// G1 points against the blob's field elements.
+    pub fn blob_to_kzg_commitment(
+        blob: &Blob,
+        kzg_settings: &KzgSettings,
+    ) -> Result<Bytes48, KzgError> {
+        let polynomial = blob.as_polynomial()?;
+        let commitment = commit_to_polynomial(&polynomial, kzg_settings)?;
+
+        Bytes48::from_slice(&commitment.to_compressed())
+    }
+
+    /// Computes the proof for the blob's polynomial at `z`, and the evaluation `y = p(z)`.
+    pub fn compute_kzg_proof(
+        blob: &Blob,
+        z_bytes: &Bytes32,
+        kzg_settings: &KzgSettings,
+    ) -> Result<(Bytes48, Bytes32), KzgError> {
+        let polynomial = blob.as_polynomial()?;
+        let z = safe_scalar_affine_from_bytes(z_bytes)?;
+
+        let (proof, y) = compute_kzg_proof_impl(&polynomial, z, kzg_settings)?;
+
+        Ok((
+            Bytes48::from_slice(&proof.to_compressed())?,
+            scalar_to_bytes32(&y),
+        ))
+    }
+
+    /// Computes the proof for the blob at the Fiat-Shamir challenge derived from the blob and
+    /// its commitment, as checked by [`KzgProof::verify_blob_kzg_proof`].
+    pub fn compute_blob_kzg_proof(
+        blob: &Blob,
+        commitment_bytes: &Bytes48,
+        kzg_settings: &KzgSettings,
+    ) -> Result<Bytes48, KzgError> {
+        // Convert commitment bytes to G1Affine
+        let commitment = safe_g1_affine_from_bytes(commitment_bytes)?;
+
+        // Convert blob to polynomial
+        let polynomial = blob.as_polynomial()?;
+
+        // Compute the evaluation challenge for the blob and commitment
+        let evaluation_challenge = compute_challenge(blob, &commitment)?;
+
+        let (proof, _) = compute_kzg_proof_impl(&polynomial, evaluation_challenge, kzg_settings)?;
+
+        Bytes48::from_slice(&proof.to_compressed())
+    }
+
+    /// Commits to a polynomial of degree below `domain_size`, a power of two of at most
+    /// [`NUM_FIELD_ELEMENTS_PER_BLOB`], given by its evaluations in bit-reversed order over the
+    /// `domain_size`-th roots of unity. These are the first `domain_size` roots of the settings.
+    ///
+    /// The commitment is the same as for the blob of the polynomial's evaluations over the whole
+    /// domain, so its proofs are checked with [`KzgProof::verify_kzg_proof`].
+    pub fn commit_with_domain_size(
+        evaluations: &[Bytes32],
+        domain_size: usize,
+        kzg_settings: &KzgSettings,
+    ) -> Result<Bytes48, KzgError> {
+        let polynomial = extend_to_blob_domain(evaluations, domain_size, kzg_settings)?;
+        let commitment = commit_to_polynomial(&polynomial, kzg_settings)?;
+
+        Bytes48::from_slice(&commitment.to_compressed())
+    }
+
+    /// Computes the proof at `z` for a polynomial committed to with
+    /// [`KzgProof::commit_with_domain_size`], and the evaluation `y = p(z)`.
+    pub fn compute_kzg_proof_with_domain_size(
+        evaluations: &[Bytes32],
+        domain_size: usize,
+        z_bytes: &Bytes32,
+        kzg_settings: &KzgSettings,
+    ) -> Result<(Bytes48, Bytes32), KzgError> {
+        let polynomial = extend_to_blob_domain(evaluations, domain_size, kzg_settings)?;
+        let z = safe_scalar_affine_from_bytes(z_bytes)?;
+
+        let (proof, y) = compute_kzg_proof_impl(&polynomial, z, kzg_settings)?;
+
+        Ok((
+            Bytes48::from_slice(&proof.to_compressed())?,
+            scalar_to_bytes32(&y),
+        ))
+    }
+
+    /// Proves that element `index` of the blob has its value, for verifiers that only hold the
+    /// commitment.
+    pub fn compute_inclusion_proof(
+        blob: &Blob,
+        index: usize,
+        kzg_settings: &KzgSettings,
+    ) -> Result<InclusionProof, KzgError> {
+        let z = domain_point(index, kzg_settings)?;
+        let polynomial = blob.as_polynomial()?;
+
+        let (proof, y) = compute_kzg_proof_impl(&polynomial, z, kzg_settings)?;
+
+        Ok(InclusionProof {
+            value: scalar_to_bytes32(&y),
+            proof: Bytes48::from_slice(&proof.to_compressed())?,
+        })
+    }
+
+    pub fn verify_inclusion_proof(
+        commitment_bytes: &Bytes48,
+        index: usize,
+        inclusion_proof: &InclusionProof,
+        kzg_settings: &KzgSettings,
+    ) -> Result<bool, KzgError> {
+        let z = domain_point(index, kzg_settings)?;
+        let y = safe_scalar_affine_from_bytes(&inclusion_proof.value)?;
+        let commitment = safe_g1_affine_from_bytes(commitment_bytes)?;
+        let proof = safe_g1_affine_from_bytes(&inclusion_proof.proof)?;
+
+        verify_kzg_proof_impl(commitment, z, y, proof, kzg_settings)
+    }
+
+    /// Verifies some elements of a blob against its commitment, without the rest of the blob:
+    /// `known_elements` holds `(index, value)` pairs and `proofs` the matching inclusion proofs,
+    /// as from [`KzgProof::compute_inclusion_proof`]. The openings are checked in one batch.
+    /// An empty `known_elements` is rejected rather than vacuously verified.
+    pub fn verify_partial_blob(
+        commitment_bytes: &Bytes48,
+        known_elements: &[(usize, Bytes32)],
+        proofs_bytes: &[Bytes48],
+        kzg_settings: &KzgSettings,
+    ) -> Result<bool, KzgError> {
+        if known_elements.is_empty() {
+            return Err(KzgError::BadArgs(
+                "Cannot verify an empty set of blob elements".to_string(),
+            ));
+        }
+
+        if known_elements.len() != proofs_bytes.len() {
+            return Err(KzgError::BadArgs(format!(
+                "Expected a proof for each of the {} elements, but got {}",
+                known_elements.len(),
+                proofs_bytes.len()
+            )));
+        }
+
+        let commitment = safe_g1_affine_from_bytes(commitment_bytes)?;
+        let (zs, ys) = known_elements
+            .iter()
+            .map(|(index, value)| {
+                Ok((
+                    domain_point(*index, kzg_settings)?,
+                    safe_scalar_affine_from_bytes(value)?,
+                ))
+            })
+            .collect::<Result<(Vec<_>, Vec<_>), KzgError>>()?;
+        let proofs = proofs_bytes
+            .iter()
+            .map(safe_g1_affine_from_bytes)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Self::verify_kzg_proof_batch(
+            &vec![commitment; proofs.len()],
+            &zs,
+            &ys,
+            &proofs,
+            kzg_settings,
+        )
+    }
+
+    /// Verifies a blob proof for a blob borrowed from the caller, who can keep it afterwards.
+    pub fn verify_blob_kzg_proof_ref(
+        blob: &Blob,
+        commitment_bytes: &Bytes48,
+        proof_bytes: &Bytes48,
+        kzg_settings: &KzgSettings,
+    ) -> Result<bool, KzgError> {
+        verify_blob_kzg_proof_impl(blob, commitment_bytes, proof_bytes, kzg_settings)
+    }
+
+    /// Verifies a blob proof, see [`KzgProof::verify_blob_kzg_proof_ref`].
+    pub fn verify_blob_kzg_proof(
+        blob: Blob,
+        commitment_bytes: &Bytes48,
+        proof_bytes: &Bytes48,
+        kzg_settings: &KzgSettings,
+    ) -> Result<bool, KzgError> {
+        Self::verify_blob_kzg_proof_ref(&blob, commitment_bytes, proof_bytes, kzg_settings)
+    }
+
+    /// [`KzgProof::verify_blob_kzg_proof`], also returning the Fiat-Shamir challenge `z` and the
+    /// blob's evaluation `y` at it, as `(valid, z, y)`.
+    pub fn verify_blob_kzg_proof_with_challenge(
+        blob: &Blob,
+        commitment_bytes: &Bytes48,
+        proof_bytes: &Bytes48,
+        kzg_settings: &KzgSettings,
+    ) -> Result<(bool, Scalar, Scalar), KzgError> {
+        // Convert commitment bytes to G1Affine
+        let commitment = safe_g1_affine_from_bytes(commitment_bytes)?;
+
+        // Convert blob to polynomial
+        let polynomial = blob.as_polynomial()?;
+
+        // Convert proof bytes to G1Affine
+        let proof = safe_g1_affine_from_bytes(proof_bytes)?;
+
+        // Compute the evaluation challenge for the blob and commitment
+        let evaluation_challenge = compute_challenge(blob, &commitment)?;
 
         // Evaluate the polynomial in evaluation form
-        let y =
-            evaluate_polynomial_in_evaluation_form(polynomial, evaluation_challenge, kzg_settings)?;
+        let y = evaluate_polynomial_in_evaluation_form(
+            &polynomial,
+            evaluation_challenge,
+            kzg_settings,
+        )?;
 
         // Verify the KZG proof
-        verify_kzg_proof_impl(commitment, evaluation_challenge, y, proof, kzg_settings)
+        let valid =
+            verify_kzg_proof_impl(commitment, evaluation_challenge, y, proof, kzg_settings)?;
+        Ok((valid, evaluation_challenge, y))
     }
 
-    pub fn verify_blob_kzg_proof_batch(
-        blobs: Vec<Blob>,
-        commitments_bytes: Vec<Bytes48>,
-        proofs_bytes: Vec<Bytes48>,
+    /// [`KzgProof::verify_blob_kzg_proof`], returning `Ok(false)` straight away if `filter`
+    /// rejects the commitment.
+    pub fn verify_blob_kzg_proof_filtered(
+        blob: &Blob,
+        commitment_bytes: &Bytes48,
+        proof_bytes: &Bytes48,
         kzg_settings: &KzgSettings,
+        filter: &impl CommitmentFilter,
     ) -> Result<bool, KzgError> {
-        if blobs.is_empty() {
-            return Ok(true);
+        if filter.is_rejected(commitment_bytes) {
+            return Ok(false);
         }
 
-        if blobs.len() == 1 {
-            return Self::verify_blob_kzg_proof(
-                blobs[0].clone(),
-                &commitments_bytes[0],
-                &proofs_bytes[0],
-                kzg_settings,
-            );
+        verify_blob_kzg_proof_impl(blob, commitment_bytes, proof_bytes, kzg_settings)
+    }
+
+    /// [`KzgProof::verify_blob_kzg_proof_batch_ref`], returning `Ok(false)` straight away if
+    /// `filter` rejects any of the commitments.
+    pub fn verify_blob_kzg_proof_batch_filtered(
+        blobs: &[Blob],
+        commitments_bytes: &[Bytes48],
+        proofs_bytes: &[Bytes48],
+        kzg_settings: &KzgSettings,
+        filter: &impl CommitmentFilter,
+    ) -> Result<bool, KzgError> {
+        if commitments_bytes
+            .iter()
+            .any(|commitment| filter.is_rejected(commitment))
+        {
+            return Ok(false);
+        }
+
+        Self::verify_blob_kzg_proof_batch_ref(blobs, commitments_bytes, proofs_bytes, kzg_settings)
+    }
+
+    /// [`KzgProof::verify_blob_kzg_proof`] with the commitment and proof as distinct types, so
+    /// swapping them does not compile.
+    pub fn verify_blob_kzg_proof_typed(
+        blob: &Blob,
+        commitment: &KzgCommitment,
+        proof: &KzgProofBytes,
+        kzg_settings: &KzgSettings,
+    ) -> Result<bool, KzgError> {
+        verify_blob_kzg_proof_impl(blob, &commitment.0, &proof.0, kzg_settings)
+    }
+
+    /// Verifies a batch of blob proofs borrowed from the caller.
+    ///
+    /// An empty batch returns `Ok(true)`, as in c-kzg: there is nothing that fails to verify.
+    /// Callers that treat "no proofs" as a failure in a security check should use
+    /// [`KzgProof::verify_blob_kzg_proof_batch_strict`] instead.
+    pub fn verify_blob_kzg_proof_batch_ref(
+        blobs: &[Blob],
+        commitments_bytes: &[Bytes48],
+        proofs_bytes: &[Bytes48],
+        kzg_settings: &KzgSettings,
+    ) -> Result<bool, KzgError> {
+        let num_inputs = blobs
+            .len()
+            .max(commitments_bytes.len())
+            .max(proofs_bytes.len());
+        if num_inputs > MAX_BLOBS_PER_BATCH {
+            return Err(KzgError::TooManyInputs(format!(
+                "At most {} blobs can be verified in a batch, but got {}",
+                MAX_BLOBS_PER_BATCH, num_inputs
+            )));
         }
 
         if blobs.len() != commitments_bytes.len() {
@@ -493,6 +1352,19 @@ impl KzgProof {
             ));
         }
 
+        if blobs.is_empty() {
+            return Ok(true);
+        }
+
+        if blobs.len() == 1 {
+            return verify_blob_kzg_proof_impl(
+                &blobs[0],
+                &commitments_bytes[0],
+                &proofs_bytes[0],
+                kzg_settings,
+            );
+        }
+
         let commitments = commitments_bytes
             .iter()
             .map(safe_g1_affine_from_bytes)
@@ -516,45 +1388,179 @@ impl KzgProof {
             kzg_settings,
         )
     }
-}
-
-#[cfg(test)]
-pub mod tests {
-    use super::*;
-    use crate::test_files::{
-        VERIFY_BLOB_KZG_PROOF_BATCH_TESTS, VERIFY_BLOB_KZG_PROOF_TESTS, VERIFY_KZG_PROOF_TESTS,
-    };
-    use serde_derive::Deserialize;
 
-    trait FromHex {
-        fn from_hex(hex: &str) -> Result<Self, KzgError>
-        where
-            Self: Sized;
+    /// Verifies a batch of blob proofs, see [`KzgProof::verify_blob_kzg_proof_batch_ref`].
+    pub fn verify_blob_kzg_proof_batch(
+        blobs: Vec<Blob>,
+        commitments_bytes: Vec<Bytes48>,
+        proofs_bytes: Vec<Bytes48>,
+        kzg_settings: &KzgSettings,
+    ) -> Result<bool, KzgError> {
+        Self::verify_blob_kzg_proof_batch_ref(
+            &blobs,
+            &commitments_bytes,
+            &proofs_bytes,
+            kzg_settings,
+        )
     }
 
-    fn hex_to_bytes(hex_str: &str) -> Result<Vec<u8>, KzgError> {
-        let trimmed_str = hex_str.strip_prefix("0x").unwrap_or(hex_str);
-        hex::decode(trimmed_str)
-            .map_err(|e| KzgError::InvalidHexFormat(format!("Failed to decode hex: {}", e)))
+    /// Verifies a batch of blob proofs and reports whether each one is valid.
+    ///
+    /// When the whole batch verifies this costs the same as
+    /// [`KzgProof::verify_blob_kzg_proof_batch_ref`]; otherwise every blob is then verified on its
+    /// own to find the invalid ones.
+    pub fn verify_blob_kzg_proof_batch_detailed(
+        blobs: &[Blob],
+        commitments_bytes: &[Bytes48],
+        proofs_bytes: &[Bytes48],
+        kzg_settings: &KzgSettings,
+    ) -> Result<Vec<bool>, KzgError> {
+        if Self::verify_blob_kzg_proof_batch_ref(
+            blobs,
+            commitments_bytes,
+            proofs_bytes,
+            kzg_settings,
+        )? {
+            return Ok(vec![true; blobs.len()]);
+        }
+
+        blobs
+            .iter()
+            .zip(commitments_bytes)
+            .zip(proofs_bytes)
+            .map(|((blob, commitment_bytes), proof_bytes)| {
+                verify_blob_kzg_proof_impl(blob, commitment_bytes, proof_bytes, kzg_settings)
+            })
+            .collect()
     }
 
-    impl FromHex for Bytes48 {
-        fn from_hex(hex_str: &str) -> Result<Self, KzgError> {
-            Self::from_slice(&hex_to_bytes(hex_str).unwrap())
+    /// Verifies a batch that the caller reordered, e.g. sorted by commitment for memory locality,
+    /// and reports whether each blob is valid in the original order. The `i`-th input was at
+    /// index `original_indices[i]` before reordering. The batch check does not depend on the
+    /// order of its inputs, so the results are the same as for the unordered batch.
+    pub fn verify_blob_kzg_proof_batch_permuted(
+        blobs: &[Blob],
+        commitments_bytes: &[Bytes48],
+        proofs_bytes: &[Bytes48],
+        original_indices: &[usize],
+        kzg_settings: &KzgSettings,
+    ) -> Result<Vec<bool>, KzgError> {
+        if original_indices.len() != blobs.len() {
+            return Err(KzgError::BadArgs(format!(
+                "Expected {} original indices, but got {}",
+                blobs.len(),
+                original_indices.len()
+            )));
+        }
+
+        let mut seen = vec![false; blobs.len()];
+        for &index in original_indices {
+            if index >= blobs.len() || core::mem::replace(&mut seen[index], true) {
+                return Err(KzgError::BadArgs(format!(
+                    "The original indices are not a permutation, {} is out of range or repeated",
+                    index
+                )));
+            }
+        }
+
+        let results = Self::verify_blob_kzg_proof_batch_detailed(
+            blobs,
+            commitments_bytes,
+            proofs_bytes,
+            kzg_settings,
+        )?;
+
+        let mut original_results = vec![false; results.len()];
+        for (&index, result) in original_indices.iter().zip(results) {
+            original_results[index] = result;
         }
+        Ok(original_results)
     }
 
-    impl FromHex for Bytes32 {
-        fn from_hex(hex_str: &str) -> Result<Self, KzgError> {
-            Self::from_slice(&hex_to_bytes(hex_str).unwrap())
+    /// Like [`KzgProof::verify_blob_kzg_proof_batch`], but rejects an empty batch with
+    /// `BadArgs` and reports a batch that does not verify as `VerificationFailed`.
+    pub fn verify_blob_kzg_proof_batch_strict(
+        blobs: Vec<Blob>,
+        commitments_bytes: Vec<Bytes48>,
+        proofs_bytes: Vec<Bytes48>,
+        kzg_settings: &KzgSettings,
+    ) -> Result<(), KzgError> {
+        if blobs.is_empty() {
+            return Err(KzgError::BadArgs(
+                "Cannot verify an empty batch of blobs".to_string(),
+            ));
+        }
+
+        if Self::verify_blob_kzg_proof_batch(blobs, commitments_bytes, proofs_bytes, kzg_settings)?
+        {
+            Ok(())
+        } else {
+            Err(KzgError::VerificationFailed(
+                "The batch of blob proofs does not verify".to_string(),
+            ))
         }
     }
 
-    impl FromHex for Blob {
-        fn from_hex(hex_str: &str) -> Result<Self, KzgError> {
-            Self::from_slice(&hex_to_bytes(hex_str).unwrap())
+    /// Verifies the blob proofs of records laid out back to back, each a blob followed by its
+    /// commitment and proof ([`BYTES_PER_BLOB_RECORD`] bytes), e.g. in a memory-mapped archive.
+    ///
+    /// Records are parsed one at a time straight from `records`, so only the points and
+    /// evaluations of each blob are held. They are verified in batches of at most
+    /// [`MAX_BLOBS_PER_BATCH`], stopping at the first batch that fails.
+    pub fn verify_blob_kzg_proof_records(
+        records: &[u8],
+        kzg_settings: &KzgSettings,
+    ) -> Result<bool, KzgError> {
+        // `is_multiple_of` needs Rust 1.87, newer than the crate otherwise requires
+        #[allow(clippy::manual_is_multiple_of)]
+        if records.len() % BYTES_PER_BLOB_RECORD != 0 {
+            return Err(KzgError::InvalidBytesLength(format!(
+                "The records should be a multiple of {} bytes, but were {}",
+                BYTES_PER_BLOB_RECORD,
+                records.len()
+            )));
+        }
+
+        for batch in records.chunks(MAX_BLOBS_PER_BATCH * BYTES_PER_BLOB_RECORD) {
+            let n = batch.len() / BYTES_PER_BLOB_RECORD;
+            let mut commitments = Vec::with_capacity(n);
+            let mut zs = Vec::with_capacity(n);
+            let mut ys = Vec::with_capacity(n);
+            let mut proofs = Vec::with_capacity(n);
+
+            for record in batch.chunks(BYTES_PER_BLOB_RECORD) {
+                let (blob, points) = record.split_at(BYTES_PER_BLOB);
+                let (commitment, proof) = points.split_at(BYTES_PER_COMMITMENT);
+                let commitment = safe_g1_affine_from_bytes(&Bytes48::from_slice(commitment)?)?;
+                let proof = safe_g1_affine_from_bytes(&Bytes48::from_slice(proof)?)?;
+                let (z, y) =
+                    compute_challenge_and_evaluate_blob_bytes(blob, &commitment, kzg_settings)?;
+
+                commitments.push(commitment);
+                zs.push(z);
+                ys.push(y);
+                proofs.push(proof);
+            }
+
+            validate_batched_input(&commitments, &proofs)?;
+            if !Self::verify_kzg_proof_batch(&commitments, &zs, &ys, &proofs, kzg_settings)? {
+                return Ok(false);
+            }
         }
+
+        Ok(true)
     }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::test_files::{
+        VERIFY_BLOB_KZG_PROOF_BATCH_TESTS, VERIFY_BLOB_KZG_PROOF_TESTS, VERIFY_KZG_PROOF_TESTS,
+    };
+    use crate::utils::{compute_roots_of_unity, hex_to_bytes};
+    use bls12_381::G2Projective;
+    use serde_derive::Deserialize;
 
     #[derive(Debug, Deserialize)]
     pub struct Input<'a> {
@@ -623,44 +1629,298 @@ pub mod tests {
         }
     }
 
-    #[derive(Debug, Deserialize)]
-    pub struct BlobInput<'a> {
-        blob: &'a str,
-        commitment: &'a str,
-        proof: &'a str,
-    }
-
-    impl BlobInput<'_> {
-        pub fn get_blob(&self) -> Result<Blob, KzgError> {
-            Blob::from_hex(self.blob)
-        }
+    #[test]
+    pub fn test_verify_kzg_proof_batch_bytes() {
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
 
-        pub fn get_commitment(&self) -> Result<Bytes48, KzgError> {
-            Bytes48::from_hex(self.commitment)
+        let (mut commitments, mut zs, mut ys, mut proofs) = (vec![], vec![], vec![], vec![]);
+        let mut incorrect = None;
+        for (_test_file, data) in VERIFY_KZG_PROOF_TESTS {
+            let test: Test<Input> = serde_yaml::from_str(data).unwrap();
+            let Some(output) = test.get_output() else {
+                continue;
+            };
+            let inputs = (
+                test.input.get_commitment().unwrap(),
+                test.input.get_z().unwrap(),
+                test.input.get_y().unwrap(),
+                test.input.get_proof().unwrap(),
+            );
+            if output {
+                commitments.push(inputs.0);
+                zs.push(inputs.1);
+                ys.push(inputs.2);
+                proofs.push(inputs.3);
+            } else {
+                incorrect = Some(inputs);
+            }
         }
+        assert!(KzgProof::verify_kzg_proof_batch_bytes(
+            &commitments,
+            &zs,
+            &ys,
+            &proofs,
+            &kzg_settings
+        )
+        .unwrap());
+
+        // One incorrect proof fails the whole batch
+        let (commitment, z, y, proof) = incorrect.unwrap();
+        let mut with_incorrect = (commitments.clone(), zs.clone(), ys.clone(), proofs.clone());
+        with_incorrect.0.push(commitment);
+        with_incorrect.1.push(z);
+        with_incorrect.2.push(y);
+        with_incorrect.3.push(proof);
+        assert!(!KzgProof::verify_kzg_proof_batch_bytes(
+            &with_incorrect.0,
+            &with_incorrect.1,
+            &with_incorrect.2,
+            &with_incorrect.3,
+            &kzg_settings
+        )
+        .unwrap());
+
+        assert!(matches!(
+            KzgProof::verify_kzg_proof_batch_bytes(
+                &commitments,
+                &zs[1..],
+                &ys,
+                &proofs,
+                &kzg_settings
+            ),
+            Err(KzgError::BadArgs(_))
+        ));
 
-        pub fn get_proof(&self) -> Result<Bytes48, KzgError> {
-            Bytes48::from_hex(self.proof)
-        }
+        let mut invalid_proofs = proofs.clone();
+        invalid_proofs[0] = Bytes48::from([0xff; 48]);
+        assert!(KzgProof::verify_kzg_proof_batch_bytes(
+            &commitments,
+            &zs,
+            &ys,
+            &invalid_proofs,
+            &kzg_settings
+        )
+        .is_err());
     }
 
     #[test]
-    pub fn test_verify_blob_kzg_proof() {
+    pub fn test_verify_kzg_proof_batch_low_memory() {
         let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
-        let test_files = VERIFY_BLOB_KZG_PROOF_TESTS;
 
-        for (_test_file, data) in test_files {
-            let test: Test<BlobInput> = serde_yaml::from_str(data).unwrap();
-            let (Ok(blob), Ok(commitment), Ok(proof)) = (
-                test.input.get_blob(),
-                test.input.get_commitment(),
-                test.input.get_proof(),
-            ) else {
-                assert!(test.get_output().is_none());
+        let (mut commitments, mut zs, mut ys, mut proofs) = (vec![], vec![], vec![], vec![]);
+        for (_test_file, data) in VERIFY_KZG_PROOF_TESTS {
+            let test: Test<Input> = serde_yaml::from_str(data).unwrap();
+            if test.get_output() != Some(true) || commitments.len() == 8 {
                 continue;
-            };
+            }
+            let commitment = test.input.get_commitment().unwrap();
+            commitments.push(safe_g1_affine_from_bytes(&commitment).unwrap());
+            zs.push(safe_scalar_affine_from_bytes(&test.input.get_z().unwrap()).unwrap());
+            ys.push(safe_scalar_affine_from_bytes(&test.input.get_y().unwrap()).unwrap());
+            proofs.push(safe_g1_affine_from_bytes(&test.input.get_proof().unwrap()).unwrap());
+        }
 
-            let result = KzgProof::verify_blob_kzg_proof(blob, &commitment, &proof, &kzg_settings);
+        for n in [0, 1, commitments.len()] {
+            let (commitments, zs, ys, proofs) =
+                (&commitments[..n], &zs[..n], &ys[..n], &proofs[..n]);
+            assert!(
+                KzgProof::verify_kzg_proof_batch(commitments, zs, ys, proofs, &kzg_settings)
+                    .unwrap()
+            );
+            assert!(KzgProof::verify_kzg_proof_batch_low_memory(
+                commitments,
+                zs,
+                ys,
+                proofs,
+                &kzg_settings
+            )
+            .unwrap());
+        }
+
+        ys[1] += Scalar::one();
+        assert!(
+            !KzgProof::verify_kzg_proof_batch(&commitments, &zs, &ys, &proofs, &kzg_settings)
+                .unwrap()
+        );
+        assert!(!KzgProof::verify_kzg_proof_batch_low_memory(
+            &commitments,
+            &zs,
+            &ys,
+            &proofs,
+            &kzg_settings
+        )
+        .unwrap());
+    }
+
+    #[test]
+    pub fn test_safe_g1_affine_from_bytes_rejects_non_canonical_encodings() {
+        let generator = G1Affine::generator().to_compressed();
+        assert!(safe_g1_affine_from_bytes(&Bytes48::from(generator)).is_ok());
+        let mut identity = [0u8; 48];
+        identity[0] = 0xc0;
+        assert!(safe_g1_affine_from_bytes(&Bytes48::from(identity)).is_ok());
+
+        // The base field modulus, as an x-coordinate with the compression flag set
+        let mut modulus: [u8; 48] = hex_to_bytes("1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        modulus[0] |= 0x80;
+
+        let mut uncompressed_flag = generator;
+        uncompressed_flag[0] &= 0x7f;
+        let mut identity_with_x = generator;
+        identity_with_x[0] |= 0x40;
+        let mut identity_with_sign = identity;
+        identity_with_sign[0] |= 0x20;
+        let mut identity_trailing_byte = identity;
+        identity_trailing_byte[47] = 1;
+        // x = 0 is not on the curve, as 4 is not a square in the base field
+        let mut not_on_curve = [0u8; 48];
+        not_on_curve[0] = 0x80;
+
+        for (name, bytes) in [
+            ("x equal to the modulus", modulus),
+            ("compression flag unset", uncompressed_flag),
+            ("infinity flag with an x-coordinate", identity_with_x),
+            ("infinity flag with the sign flag", identity_with_sign),
+            ("infinity flag with a trailing byte", identity_trailing_byte),
+            ("not on the curve", not_on_curve),
+        ] {
+            assert!(
+                safe_g1_affine_from_bytes(&Bytes48::from(bytes)).is_err(),
+                "accepted an encoding with {}",
+                name
+            );
+        }
+
+        // Flipping the sign flag gives the negated point, not another encoding of the same one
+        let mut negated = generator;
+        negated[0] ^= 0x20;
+        assert_eq!(
+            safe_g1_affine_from_bytes(&Bytes48::from(negated)).unwrap(),
+            -G1Affine::generator()
+        );
+    }
+
+    #[test]
+    pub fn test_verify_kzg_proof_checked() {
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+
+        for (_test_file, data) in VERIFY_KZG_PROOF_TESTS {
+            let test: Test<Input> = serde_yaml::from_str(data).unwrap();
+            if test.get_output() != Some(true) {
+                continue;
+            }
+            let commitment = test.input.get_commitment().unwrap();
+            let z = test.input.get_z().unwrap();
+            let y = test.input.get_y().unwrap();
+            let proof = test.input.get_proof().unwrap();
+
+            assert!(KzgProof::verify_kzg_proof_checked(
+                &commitment,
+                &commitment,
+                &z,
+                &y,
+                &proof,
+                &kzg_settings
+            )
+            .unwrap());
+
+            // The proof is valid for the commitment, but it is not the expected one
+            let expected = Bytes48::from(G1Affine::generator().to_compressed());
+            assert_ne!(expected, commitment);
+            assert!(!KzgProof::verify_kzg_proof_checked(
+                &expected,
+                &commitment,
+                &z,
+                &y,
+                &proof,
+                &kzg_settings
+            )
+            .unwrap());
+        }
+    }
+
+    #[test]
+    pub fn test_verify_kzg_proof_pairing_inputs() {
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+
+        for (_test_file, data) in VERIFY_KZG_PROOF_TESTS {
+            let test: Test<Input> = serde_yaml::from_str(data).unwrap();
+            let (Ok(commitment), Ok(z), Ok(y), Ok(proof)) = (
+                test.input.get_commitment(),
+                test.input.get_z(),
+                test.input.get_y(),
+                test.input.get_proof(),
+            ) else {
+                continue;
+            };
+
+            let Ok(inputs) = KzgProof::verify_kzg_proof_pairing_inputs(
+                &commitment,
+                &z,
+                &y,
+                &proof,
+                &kzg_settings,
+            ) else {
+                assert!(test.get_output().is_none());
+                continue;
+            };
+
+            assert_eq!(
+                KzgProof::reconstruct_p_minus_y(&commitment, &y).unwrap(),
+                inputs.p_minus_y
+            );
+
+            let expected = test.get_output().unwrap();
+            assert_eq!(inputs.verify(), expected);
+            assert_eq!(
+                bls12_381::pairing(&inputs.p_minus_y, &inputs.generator)
+                    == bls12_381::pairing(&inputs.proof, &inputs.x_minus_z),
+                expected
+            );
+        }
+    }
+
+    #[derive(Debug, Deserialize)]
+    pub struct BlobInput<'a> {
+        blob: &'a str,
+        commitment: &'a str,
+        proof: &'a str,
+    }
+
+    impl BlobInput<'_> {
+        pub fn get_blob(&self) -> Result<Blob, KzgError> {
+            Blob::from_hex(self.blob)
+        }
+
+        pub fn get_commitment(&self) -> Result<Bytes48, KzgError> {
+            Bytes48::from_hex(self.commitment)
+        }
+
+        pub fn get_proof(&self) -> Result<Bytes48, KzgError> {
+            Bytes48::from_hex(self.proof)
+        }
+    }
+
+    #[test]
+    pub fn test_verify_blob_kzg_proof() {
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+        let test_files = VERIFY_BLOB_KZG_PROOF_TESTS;
+
+        for (_test_file, data) in test_files {
+            let test: Test<BlobInput> = serde_yaml::from_str(data).unwrap();
+            let (Ok(blob), Ok(commitment), Ok(proof)) = (
+                test.input.get_blob(),
+                test.input.get_commitment(),
+                test.input.get_proof(),
+            ) else {
+                assert!(test.get_output().is_none());
+                continue;
+            };
+
+            let result = KzgProof::verify_blob_kzg_proof(blob, &commitment, &proof, &kzg_settings);
             match result {
                 Ok(result) => {
                     assert_eq!(result, test.get_output().unwrap_or(false));
@@ -672,6 +1932,28 @@ pub mod tests {
         }
     }
 
+    #[test]
+    pub fn test_verify_blob_kzg_proof_ref() {
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+        let data = include_str!("../tests/verify_blob_kzg_proof/verify_blob_kzg_proof_case_correct_proof_fb324bc819407148/data.yaml");
+        let test: Test<BlobInput> = serde_yaml::from_str(data).unwrap();
+        let blob = test.input.get_blob().unwrap();
+        let commitment = test.input.get_commitment().unwrap();
+        let proof = test.input.get_proof().unwrap();
+
+        // The blob is still ours after each call
+        for _ in 0..2 {
+            assert!(
+                KzgProof::verify_blob_kzg_proof_ref(&blob, &commitment, &proof, &kzg_settings)
+                    .unwrap()
+            );
+        }
+        assert!(
+            !KzgProof::verify_blob_kzg_proof_ref(&blob, &proof, &commitment, &kzg_settings)
+                .unwrap()
+        );
+    }
+
     #[derive(Debug, Deserialize)]
     struct BlobBatchInput<'a> {
         #[serde(borrow)]
@@ -696,6 +1978,43 @@ pub mod tests {
         }
     }
 
+    #[test]
+    pub fn test_verify_blob_kzg_proof_records() {
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+
+        let mut valid_records = vec![];
+        let mut invalid_record = None;
+        for (_test_file, data) in VERIFY_BLOB_KZG_PROOF_TESTS {
+            let test: Test<BlobInput> = serde_yaml::from_str(data).unwrap();
+            let Some(output) = test.get_output() else {
+                continue;
+            };
+            let record = [
+                test.input.get_blob().unwrap().as_slice(),
+                test.input.get_commitment().unwrap().as_slice(),
+                test.input.get_proof().unwrap().as_slice(),
+            ]
+            .concat();
+            if output {
+                valid_records.extend(record);
+            } else {
+                invalid_record = Some(record);
+            }
+        }
+        assert!(valid_records.len() > BYTES_PER_BLOB_RECORD);
+
+        assert!(KzgProof::verify_blob_kzg_proof_records(&valid_records, &kzg_settings).unwrap());
+        assert!(KzgProof::verify_blob_kzg_proof_records(&[], &kzg_settings).unwrap());
+
+        let with_invalid = [valid_records.as_slice(), &invalid_record.unwrap()].concat();
+        assert!(!KzgProof::verify_blob_kzg_proof_records(&with_invalid, &kzg_settings).unwrap());
+
+        assert!(matches!(
+            KzgProof::verify_blob_kzg_proof_records(&valid_records[1..], &kzg_settings),
+            Err(KzgError::InvalidBytesLength(_))
+        ));
+    }
+
     #[test]
     pub fn test_verify_blob_kzg_proof_batch() {
         let test_files = VERIFY_BLOB_KZG_PROOF_BATCH_TESTS;
@@ -729,6 +2048,979 @@ pub mod tests {
         }
     }
 
+    #[test]
+    pub fn test_verify_blob_kzg_proof_batch_mismatched_lengths() {
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+        let blob =
+            Blob::from_field_elements(&[Scalar::zero(); NUM_FIELD_ELEMENTS_PER_BLOB]).unwrap();
+        let identity = Bytes48::from(G1Affine::identity().to_compressed());
+
+        for (blobs, commitments, proofs) in [
+            (vec![blob.clone()], vec![], vec![identity.clone()]),
+            (
+                vec![blob.clone()],
+                vec![identity.clone(); 3],
+                vec![identity.clone()],
+            ),
+            (vec![], vec![identity.clone()], vec![]),
+        ] {
+            assert!(matches!(
+                KzgProof::verify_blob_kzg_proof_batch_ref(
+                    &blobs,
+                    &commitments,
+                    &proofs,
+                    &kzg_settings
+                ),
+                Err(KzgError::InvalidBytesLength(_))
+            ));
+        }
+
+        // The variants built on the batch check reject them too instead of panicking
+        let blobs = [blob];
+        let proofs = [identity];
+        assert!(KzgProof::verify_blob_kzg_proof_batch_detailed(
+            &blobs,
+            &[],
+            &proofs,
+            &kzg_settings
+        )
+        .is_err());
+        assert!(KzgProof::verify_blob_kzg_proof_batch_permuted(
+            &blobs,
+            &[],
+            &proofs,
+            &[0],
+            &kzg_settings
+        )
+        .is_err());
+        assert!(KzgProof::verify_blob_kzg_proof_batch_strict(
+            blobs.to_vec(),
+            vec![],
+            proofs.to_vec(),
+            &kzg_settings
+        )
+        .is_err());
+        assert!(KzgProof::verify_blob_kzg_proof_batch_filtered(
+            &blobs,
+            &[],
+            &proofs,
+            &kzg_settings,
+            &|_: &Bytes48| false
+        )
+        .is_err());
+    }
+
+    #[test]
+    pub fn test_verify_blob_kzg_proof_batch_ref() {
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+
+        // A batch of one takes the single proof path
+        for (_test_file, data) in VERIFY_BLOB_KZG_PROOF_TESTS {
+            let test: Test<BlobInput> = serde_yaml::from_str(data).unwrap();
+            let (Ok(blob), Ok(commitment), Ok(proof)) = (
+                test.input.get_blob(),
+                test.input.get_commitment(),
+                test.input.get_proof(),
+            ) else {
+                continue;
+            };
+
+            let result = KzgProof::verify_blob_kzg_proof_batch_ref(
+                core::slice::from_ref(&blob),
+                core::slice::from_ref(&commitment),
+                core::slice::from_ref(&proof),
+                &kzg_settings,
+            );
+            match result {
+                Ok(result) => {
+                    assert_eq!(result, test.get_output().unwrap_or(false));
+                }
+                Err(_) => {
+                    assert!(test.get_output().is_none());
+                }
+            }
+            assert_eq!(
+                result.ok(),
+                KzgProof::verify_blob_kzg_proof_ref(&blob, &commitment, &proof, &kzg_settings).ok()
+            );
+        }
+    }
+
+    #[test]
+    pub fn test_blob_to_kzg_commitment() {
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+
+        for (_test_file, data) in VERIFY_BLOB_KZG_PROOF_TESTS {
+            let test: Test<BlobInput> = serde_yaml::from_str(data).unwrap();
+            if test.get_output() != Some(true) {
+                continue;
+            }
+            let blob = test.input.get_blob().unwrap();
+            let expected = test.input.get_commitment().unwrap();
+
+            let commitment = KzgProof::blob_to_kzg_commitment(&blob, &kzg_settings).unwrap();
+            assert_eq!(commitment.as_slice(), expected.as_slice());
+        }
+    }
+
+    #[test]
+    pub fn test_compute_kzg_proof() {
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+        let data = include_str!("../tests/verify_blob_kzg_proof/verify_blob_kzg_proof_case_correct_proof_fb324bc819407148/data.yaml");
+        let test: Test<BlobInput> = serde_yaml::from_str(data).unwrap();
+        let blob = test.input.get_blob().unwrap();
+        let commitment = test.input.get_commitment().unwrap();
+        let polynomial = blob.as_polynomial().unwrap();
+
+        // A point outside the domain, and the root of unity at index 5
+        let root_bytes = scalar_to_bytes32(&kzg_settings.roots_of_unity[5]);
+        let z_values = [Bytes32::from_slice(&[0x42; 32]).unwrap(), root_bytes];
+
+        for (i, z) in z_values.iter().enumerate() {
+            let (proof, y) = KzgProof::compute_kzg_proof(&blob, z, &kzg_settings).unwrap();
+            if i == 1 {
+                assert_eq!(safe_scalar_affine_from_bytes(&y).unwrap(), polynomial[5]);
+            }
+
+            assert!(KzgProof::verify_kzg_proof(&commitment, z, &y, &proof, &kzg_settings).unwrap());
+
+            let wrong_y = Bytes32::from_slice(&[0; 32]).unwrap();
+            assert!(
+                !KzgProof::verify_kzg_proof(&commitment, z, &wrong_y, &proof, &kzg_settings)
+                    .unwrap()
+            );
+        }
+    }
+
+    #[test]
+    pub fn test_verify_kzg_proof_with_blob() {
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+        let data = include_str!("../tests/verify_blob_kzg_proof/verify_blob_kzg_proof_case_correct_proof_fb324bc819407148/data.yaml");
+        let test: Test<BlobInput> = serde_yaml::from_str(data).unwrap();
+        let blob = test.input.get_blob().unwrap();
+        let commitment = test.input.get_commitment().unwrap();
+
+        let z = Bytes32::from([0x42; 32]);
+        let (proof, y) = KzgProof::compute_kzg_proof(&blob, &z, &kzg_settings).unwrap();
+        assert!(KzgProof::verify_kzg_proof_with_blob(
+            &blob,
+            &commitment,
+            &z,
+            &y,
+            &proof,
+            &kzg_settings
+        )
+        .unwrap());
+
+        // The proof verifies on its own, but not against a blob with a different value at z
+        let other_data = include_str!("../tests/verify_blob_kzg_proof/verify_blob_kzg_proof_case_correct_proof_19b3f3f8c98ea31e/data.yaml");
+        let other_test: Test<BlobInput> = serde_yaml::from_str(other_data).unwrap();
+        let other_blob = other_test.input.get_blob().unwrap();
+        assert!(KzgProof::verify_kzg_proof(&commitment, &z, &y, &proof, &kzg_settings).unwrap());
+        assert!(!KzgProof::verify_kzg_proof_with_blob(
+            &other_blob,
+            &commitment,
+            &z,
+            &y,
+            &proof,
+            &kzg_settings
+        )
+        .unwrap());
+
+        // A malformed commitment is an error, even when the blob does not match y
+        let malformed = Bytes48::from([0xff; 48]);
+        assert!(matches!(
+            KzgProof::verify_kzg_proof(&malformed, &z, &y, &proof, &kzg_settings),
+            Err(KzgError::BadArgs(_))
+        ));
+        assert!(matches!(
+            KzgProof::verify_kzg_proof_with_blob(
+                &other_blob,
+                &malformed,
+                &z,
+                &y,
+                &proof,
+                &kzg_settings
+            ),
+            Err(KzgError::BadArgs(_))
+        ));
+    }
+
+    #[test]
+    pub fn test_compute_blob_kzg_proof() {
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+        let data = include_str!("../tests/verify_blob_kzg_proof/verify_blob_kzg_proof_case_correct_proof_19b3f3f8c98ea31e/data.yaml");
+        let test: Test<BlobInput> = serde_yaml::from_str(data).unwrap();
+        let blob = test.input.get_blob().unwrap();
+        let commitment = test.input.get_commitment().unwrap();
+
+        let proof = KzgProof::compute_blob_kzg_proof(&blob, &commitment, &kzg_settings).unwrap();
+        assert_eq!(proof.as_slice(), test.input.get_proof().unwrap().as_slice());
+        assert!(
+            KzgProof::verify_blob_kzg_proof(blob.clone(), &commitment, &proof, &kzg_settings)
+                .unwrap()
+        );
+
+        // A commitment that is not a valid point is rejected
+        let invalid_commitment = Bytes48::from_slice(&[0xff; 48]).unwrap();
+        assert!(
+            KzgProof::compute_blob_kzg_proof(&blob, &invalid_commitment, &kzg_settings).is_err()
+        );
+    }
+
+    #[test]
+    pub fn test_compute_blob_kzg_proof_vectors() {
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+
+        for (_test_file, data) in VERIFY_BLOB_KZG_PROOF_TESTS {
+            let test: Test<BlobInput> = serde_yaml::from_str(data).unwrap();
+            let (Ok(blob), Ok(commitment)) = (test.input.get_blob(), test.input.get_commitment())
+            else {
+                continue;
+            };
+            let Ok(proof) = KzgProof::compute_blob_kzg_proof(&blob, &commitment, &kzg_settings)
+            else {
+                assert!(test.get_output().is_none());
+                continue;
+            };
+
+            let verified =
+                KzgProof::verify_blob_kzg_proof(blob.clone(), &commitment, &proof, &kzg_settings)
+                    .unwrap();
+            if test.get_output() == Some(true) {
+                assert_eq!(proof.as_slice(), test.input.get_proof().unwrap().as_slice());
+                assert!(verified);
+            } else {
+                // The proof is only for this commitment if it commits to the blob
+                let blob_commitment =
+                    KzgProof::blob_to_kzg_commitment(&blob, &kzg_settings).unwrap();
+                assert_eq!(
+                    verified,
+                    blob_commitment.as_slice() == commitment.as_slice()
+                );
+            }
+        }
+    }
+
+    #[test]
+    pub fn test_estimate_batch_cost() {
+        assert_eq!(estimate_batch_cost(0), BatchCostEstimate::default());
+
+        let single = estimate_batch_cost(1);
+        assert_eq!(single.point_decompressions, 2);
+        assert_eq!(single.field_inversions, 1);
+        assert!(single.msm_sizes.is_empty());
+        assert_eq!(single.pairings, 2);
+
+        let batch = estimate_batch_cost(64);
+        assert_eq!(batch.point_decompressions, 128);
+        assert_eq!(batch.msm_sizes, vec![64; 3]);
+        assert_eq!(batch.pairings, 2);
+        assert!(batch.sha256_bytes > 64 * BYTES_PER_BLOB);
+        assert!(batch.field_multiplications > 64 * single.field_multiplications);
+    }
+
+    #[test]
+    pub fn test_batch_inversion() {
+        let values = compute_powers(&Scalar::from(0x1234_5678u64), 16)
+            .iter()
+            .map(|power| power + Scalar::from(3))
+            .collect::<Vec<_>>();
+
+        let mut inverses = vec![Scalar::zero(); values.len()];
+        batch_inversion(&mut inverses, &values).unwrap();
+        for (value, inverse) in values.iter().zip(&inverses) {
+            assert_eq!(value * inverse, Scalar::one());
+        }
+
+        let mut reinverted = vec![Scalar::zero(); values.len()];
+        batch_inversion(&mut reinverted, &inverses).unwrap();
+        assert_eq!(reinverted, values);
+
+        let mut with_zero = values.clone();
+        with_zero[7] = Scalar::zero();
+        assert!(matches!(
+            batch_inversion(&mut inverses, &with_zero),
+            Err(KzgError::BadArgs(_))
+        ));
+        let mut same = values.clone();
+        assert!(matches!(
+            batch_inversion(&mut same, &values),
+            Err(KzgError::BadArgs(_))
+        ));
+        assert!(matches!(
+            batch_inversion(&mut inverses[1..], &values),
+            Err(KzgError::BadArgs(_))
+        ));
+    }
+
+    #[test]
+    pub fn test_compute_vanishing_poly_commitment() {
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+
+        // (x - 2)(x - 3) = 6 - 5x + x^2
+        let points = [Scalar::from(2), Scalar::from(3)];
+        assert_eq!(
+            vanishing_polynomial(&points),
+            vec![Scalar::from(6), -Scalar::from(5), Scalar::one()]
+        );
+
+        // Committing to the constant 1 gives the generator, and to x - 2 gives [tau - 2]
+        assert_eq!(
+            compute_vanishing_poly_commitment(&[], &kzg_settings).unwrap(),
+            G1Affine::generator()
+        );
+        let x_minus_2 = compute_vanishing_poly_commitment(&points[..1], &kzg_settings).unwrap();
+        let g2 = G2Projective::generator();
+        let tau_minus_2 = G2Affine::from(kzg_settings.g2_points[1] - g2 * points[0]);
+        assert!(pairings_verify(
+            x_minus_2,
+            G2Affine::generator(),
+            G1Affine::generator(),
+            tau_minus_2
+        ));
+
+        // e([(tau - 2)(tau - 3)], G2) == e([tau - 2], [tau - 3])
+        let commitment = compute_vanishing_poly_commitment(&points, &kzg_settings).unwrap();
+        let tau_minus_3 = G2Affine::from(kzg_settings.g2_points[1] - g2 * points[1]);
+        assert!(pairings_verify(
+            commitment,
+            G2Affine::generator(),
+            x_minus_2,
+            tau_minus_3
+        ));
+
+        let too_many = vec![Scalar::one(); NUM_FIELD_ELEMENTS_PER_BLOB];
+        assert!(compute_vanishing_poly_commitment(&too_many, &kzg_settings).is_err());
+    }
+
+    #[test]
+    pub fn test_is_in_evaluation_domain() {
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+
+        assert!(is_in_evaluation_domain(&Scalar::one(), &kzg_settings));
+        assert!(is_in_evaluation_domain(&-Scalar::one(), &kzg_settings));
+        for root in kzg_settings.roots_of_unity.iter().step_by(97) {
+            assert!(is_in_evaluation_domain(root, &kzg_settings));
+            // The domain contains -1, so it is closed under negation
+            assert!(is_in_evaluation_domain(&-root, &kzg_settings));
+            assert!(!is_in_evaluation_domain(
+                &(root + Scalar::one()),
+                &kzg_settings
+            ));
+        }
+        assert!(!is_in_evaluation_domain(&Scalar::zero(), &kzg_settings));
+    }
+
+    #[test]
+    pub fn test_evaluate_at_root_missing_from_domain() {
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+        let root = kzg_settings.roots_of_unity[5];
+        let polynomial = compute_powers(&Scalar::from(3u64), NUM_FIELD_ELEMENTS_PER_BLOB);
+
+        assert_eq!(
+            evaluate_polynomial_in_evaluation_form(&polynomial, root, &kzg_settings).unwrap(),
+            polynomial[5]
+        );
+
+        // A malformed domain without this root must not evaluate every polynomial to zero there
+        let mut roots_of_unity = kzg_settings.roots_of_unity.to_vec();
+        roots_of_unity[5] += Scalar::one();
+        let malformed = KzgSettings {
+            roots_of_unity: roots_of_unity.into(),
+            ..kzg_settings
+        };
+        assert!(matches!(
+            evaluate_polynomial_in_evaluation_form(&polynomial, root, &malformed),
+            Err(KzgError::InvalidTrustedSetup(_))
+        ));
+    }
+
+    #[test]
+    pub fn test_small_domain_by_hand() {
+        // A 4-element domain, {1, -1, i, -i} in bit-reversed order, with the secret 5
+        let roots_of_unity = compute_roots_of_unity(4).unwrap();
+        let secret = Scalar::from(5);
+        let g1_points = roots_of_unity
+            .iter()
+            .map(|root| {
+                // [L(secret)]G1 for the Lagrange basis polynomial L that is one at this root
+                let basis = roots_of_unity
+                    .iter()
+                    .filter(|other| *other != root)
+                    .fold(Scalar::one(), |acc, other| {
+                        acc * (secret - other) * (root - other).invert().unwrap()
+                    });
+                G1Affine::from(G1Affine::generator() * basis)
+            })
+            .collect::<Vec<_>>();
+        let g2_points = vec![
+            G2Affine::generator(),
+            (G2Affine::generator() * secret).into(),
+        ];
+        let kzg_settings =
+            KzgSettings::from_parts_unchecked(roots_of_unity.clone(), g1_points.clone(), g2_points);
+
+        // p(x) = x^2 + 2x + 3
+        let p = |x: Scalar| x * x + x * Scalar::from(2) + Scalar::from(3);
+        let evaluations = roots_of_unity
+            .iter()
+            .map(|root| p(*root))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            lagrange_to_monomial(&evaluations, &kzg_settings).unwrap(),
+            [3, 2, 1, 0].map(Scalar::from).to_vec()
+        );
+        assert!(is_in_evaluation_domain(&-Scalar::one(), &kzg_settings));
+        assert!(!is_in_evaluation_domain(&Scalar::from(2), &kzg_settings));
+
+        // The commitment is [p(5)]G1 = [38]G1, and the proof at 2, where p(2) = 11, is
+        // [(38 - 11) / (5 - 2)]G1 = [9]G1
+        let g1_points = g1_points.iter().map(G1Projective::from).collect::<Vec<_>>();
+        let commitment = G1Affine::from(msm_variable_base(&g1_points, &evaluations));
+        assert_eq!(
+            commitment,
+            G1Affine::from(G1Affine::generator() * Scalar::from(38))
+        );
+        let proof = G1Affine::from(G1Affine::generator() * Scalar::from(9));
+        let commitment = Bytes48::from(commitment.to_compressed());
+        let proof = Bytes48::from(proof.to_compressed());
+        let (z, y) = (
+            scalar_to_bytes32(&Scalar::from(2)),
+            scalar_to_bytes32(&Scalar::from(11)),
+        );
+        assert!(KzgProof::verify_kzg_proof(&commitment, &z, &y, &proof, &kzg_settings).unwrap());
+        let wrong_y = scalar_to_bytes32(&Scalar::from(12));
+        assert!(
+            !KzgProof::verify_kzg_proof(&commitment, &z, &wrong_y, &proof, &kzg_settings).unwrap()
+        );
+    }
+
+    #[test]
+    pub fn test_custom_domain_size() {
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+        let domain_size = 256;
+        let evaluations = compute_powers(&Scalar::from(0x0bad_cafeu64), domain_size)
+            .iter()
+            .map(scalar_to_bytes32)
+            .collect::<Vec<_>>();
+        let commitment =
+            KzgProof::commit_with_domain_size(&evaluations, domain_size, &kzg_settings).unwrap();
+
+        // At a point of the subdomain, the proof opens to the evaluation there
+        for (z, expected_y) in [
+            (
+                scalar_to_bytes32(&kzg_settings.roots_of_unity[5]),
+                Some(&evaluations[5]),
+            ),
+            (scalar_to_bytes32(&Scalar::from(0x1234u64)), None),
+        ] {
+            let (proof, y) = KzgProof::compute_kzg_proof_with_domain_size(
+                &evaluations,
+                domain_size,
+                &z,
+                &kzg_settings,
+            )
+            .unwrap();
+            if let Some(expected_y) = expected_y {
+                assert_eq!(&y, expected_y);
+            }
+            assert!(
+                KzgProof::verify_kzg_proof(&commitment, &z, &y, &proof, &kzg_settings).unwrap()
+            );
+        }
+
+        // Over the whole domain, this is the blob commitment
+        let data = include_str!("../tests/verify_blob_kzg_proof/verify_blob_kzg_proof_case_correct_proof_fb324bc819407148/data.yaml");
+        let test: Test<BlobInput> = serde_yaml::from_str(data).unwrap();
+        let blob = test.input.get_blob().unwrap();
+        let blob_evaluations = blob
+            .chunks(BYTES_PER_FIELD_ELEMENT)
+            .map(|chunk| Bytes32::from_slice(chunk).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            KzgProof::commit_with_domain_size(
+                &blob_evaluations,
+                NUM_FIELD_ELEMENTS_PER_BLOB,
+                &kzg_settings
+            )
+            .unwrap(),
+            test.input.get_commitment().unwrap()
+        );
+
+        for domain_size in [0, 3, 2 * NUM_FIELD_ELEMENTS_PER_BLOB] {
+            assert!(matches!(
+                KzgProof::commit_with_domain_size(&evaluations, domain_size, &kzg_settings),
+                Err(KzgError::BadArgs(_))
+            ));
+        }
+        assert!(matches!(
+            KzgProof::commit_with_domain_size(&evaluations[1..], domain_size, &kzg_settings),
+            Err(KzgError::BadArgs(_))
+        ));
+    }
+
+    #[test]
+    pub fn test_verify_partial_blob() {
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+        let data = include_str!("../tests/verify_blob_kzg_proof/verify_blob_kzg_proof_case_correct_proof_fb324bc819407148/data.yaml");
+        let test: Test<BlobInput> = serde_yaml::from_str(data).unwrap();
+        let blob = test.input.get_blob().unwrap();
+        let commitment = test.input.get_commitment().unwrap();
+
+        let (mut known_elements, mut proofs) = (vec![], vec![]);
+        for index in [0, 7, NUM_FIELD_ELEMENTS_PER_BLOB - 1] {
+            let inclusion_proof =
+                KzgProof::compute_inclusion_proof(&blob, index, &kzg_settings).unwrap();
+            known_elements.push((index, inclusion_proof.value));
+            proofs.push(inclusion_proof.proof);
+        }
+        assert!(KzgProof::verify_partial_blob(
+            &commitment,
+            &known_elements,
+            &proofs,
+            &kzg_settings
+        )
+        .unwrap());
+
+        let mut tampered = known_elements.clone();
+        tampered[1].1 = Bytes32::from([0; 32]);
+        assert!(
+            !KzgProof::verify_partial_blob(&commitment, &tampered, &proofs, &kzg_settings).unwrap()
+        );
+
+        let mut out_of_range = known_elements.clone();
+        out_of_range[0].0 = NUM_FIELD_ELEMENTS_PER_BLOB;
+        assert!(
+            KzgProof::verify_partial_blob(&commitment, &out_of_range, &proofs, &kzg_settings)
+                .is_err()
+        );
+        assert!(KzgProof::verify_partial_blob(
+            &commitment,
+            &known_elements,
+            &proofs[1..],
+            &kzg_settings
+        )
+        .is_err());
+
+        assert!(matches!(
+            KzgProof::verify_partial_blob(&commitment, &[], &[], &kzg_settings),
+            Err(KzgError::BadArgs(_))
+        ));
+        assert!(matches!(
+            KzgProof::verify_partial_blob(&commitment, &[], &proofs, &kzg_settings),
+            Err(KzgError::BadArgs(_))
+        ));
+    }
+
+    #[test]
+    pub fn test_inclusion_proof() {
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+        let data = include_str!("../tests/verify_blob_kzg_proof/verify_blob_kzg_proof_case_correct_proof_fb324bc819407148/data.yaml");
+        let test: Test<BlobInput> = serde_yaml::from_str(data).unwrap();
+        let blob = test.input.get_blob().unwrap();
+        let commitment = test.input.get_commitment().unwrap();
+
+        let index = 7;
+        let inclusion_proof =
+            KzgProof::compute_inclusion_proof(&blob, index, &kzg_settings).unwrap();
+        assert_eq!(
+            inclusion_proof.value.as_slice(),
+            &blob.as_slice()[index * 32..(index + 1) * 32]
+        );
+        assert!(KzgProof::verify_inclusion_proof(
+            &commitment,
+            index,
+            &inclusion_proof,
+            &kzg_settings
+        )
+        .unwrap());
+
+        assert!(!KzgProof::verify_inclusion_proof(
+            &commitment,
+            index + 1,
+            &inclusion_proof,
+            &kzg_settings
+        )
+        .unwrap());
+
+        let tampered = InclusionProof {
+            value: Bytes32::from_slice(&[0; 32]).unwrap(),
+            ..inclusion_proof.clone()
+        };
+        assert!(
+            !KzgProof::verify_inclusion_proof(&commitment, index, &tampered, &kzg_settings)
+                .unwrap()
+        );
+
+        assert!(KzgProof::compute_inclusion_proof(
+            &blob,
+            NUM_FIELD_ELEMENTS_PER_BLOB,
+            &kzg_settings
+        )
+        .is_err());
+        assert!(KzgProof::verify_inclusion_proof(
+            &commitment,
+            NUM_FIELD_ELEMENTS_PER_BLOB,
+            &inclusion_proof,
+            &kzg_settings
+        )
+        .is_err());
+    }
+
+    #[test]
+    pub fn test_compute_powers() {
+        let base = Scalar::from(3);
+        assert!(compute_powers(&base, 0).is_empty());
+        assert_eq!(compute_powers(&base, 1), vec![Scalar::one()]);
+        assert_eq!(
+            compute_powers(&base, 5),
+            [1, 3, 9, 27, 81].map(Scalar::from).to_vec()
+        );
+
+        let mut out = [Scalar::zero(); 5];
+        compute_powers_into(&base, &mut out);
+        assert_eq!(out.to_vec(), compute_powers(&base, 5));
+        compute_powers_into(&base, &mut []);
+    }
+
+    #[test]
+    pub fn test_compute_r_powers() {
+        let commitments = [G1Affine::generator(), G1Affine::identity()];
+        let zs = [Scalar::from(1u64), Scalar::from(2u64)];
+        let ys = [Scalar::from(3u64), Scalar::from(4u64)];
+        let proofs = [G1Affine::identity(), G1Affine::generator()];
+
+        let mut transcript = Vec::new();
+        transcript.extend_from_slice(b"RCKZGBATCH___V1_");
+        transcript.extend_from_slice(&4096u64.to_be_bytes());
+        transcript.extend_from_slice(&2u64.to_be_bytes());
+        for i in 0..2 {
+            transcript.extend_from_slice(&commitments[i].to_compressed());
+            transcript.extend_from_slice(&[0; 31]);
+            transcript.push(i as u8 + 1);
+            transcript.extend_from_slice(&[0; 31]);
+            transcript.push(i as u8 + 3);
+            transcript.extend_from_slice(&proofs[i].to_compressed());
+        }
+        let r = scalar_from_bytes_unchecked(Sha256::digest(&transcript).into());
+
+        let r_powers = compute_r_powers(&commitments, &zs, &ys, &proofs).unwrap();
+        assert_eq!(r_powers, vec![Scalar::one(), r]);
+
+        assert!(compute_r_powers(&commitments, &zs[..1], &ys, &proofs).is_err());
+    }
+
+    #[test]
+    pub fn test_verify_blob_kzg_proof_batch_too_many_inputs() {
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+        let commitment = Bytes48::from_slice(&G1Affine::identity().to_compressed()).unwrap();
+
+        let result = KzgProof::verify_blob_kzg_proof_batch(
+            vec![],
+            vec![commitment.clone(); MAX_BLOBS_PER_BATCH + 1],
+            vec![commitment; MAX_BLOBS_PER_BATCH + 1],
+            &kzg_settings,
+        );
+        assert!(matches!(result, Err(KzgError::TooManyInputs(_))));
+    }
+
+    #[test]
+    pub fn test_verify_blob_kzg_proof_typed() {
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+
+        for (_test_file, data) in VERIFY_BLOB_KZG_PROOF_TESTS {
+            let test: Test<BlobInput> = serde_yaml::from_str(data).unwrap();
+            let (Ok(blob), Ok(commitment), Ok(proof)) = (
+                test.input.get_blob(),
+                test.input.get_commitment(),
+                test.input.get_proof(),
+            ) else {
+                continue;
+            };
+
+            let expected =
+                KzgProof::verify_blob_kzg_proof(blob.clone(), &commitment, &proof, &kzg_settings);
+            let result = KzgProof::verify_blob_kzg_proof_typed(
+                &blob,
+                &KzgCommitment::from(commitment),
+                &KzgProofBytes::from(proof),
+                &kzg_settings,
+            );
+            assert_eq!(result.ok(), expected.ok());
+        }
+    }
+
+    #[test]
+    pub fn test_verify_blob_kzg_proof_filtered() {
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+
+        let (mut blobs, mut commitments, mut proofs) = (vec![], vec![], vec![]);
+        for (_test_file, data) in VERIFY_BLOB_KZG_PROOF_TESTS {
+            let test: Test<BlobInput> = serde_yaml::from_str(data).unwrap();
+            if test.get_output() == Some(true) {
+                blobs.push(test.input.get_blob().unwrap());
+                commitments.push(test.input.get_commitment().unwrap());
+                proofs.push(test.input.get_proof().unwrap());
+            }
+        }
+        let rejected = commitments[1].clone();
+        let filter = |commitment: &Bytes48| *commitment == rejected;
+
+        assert!(KzgProof::verify_blob_kzg_proof_filtered(
+            &blobs[0],
+            &commitments[0],
+            &proofs[0],
+            &kzg_settings,
+            &filter
+        )
+        .unwrap());
+        assert!(!KzgProof::verify_blob_kzg_proof_filtered(
+            &blobs[1],
+            &commitments[1],
+            &proofs[1],
+            &kzg_settings,
+            &filter
+        )
+        .unwrap());
+        assert!(!KzgProof::verify_blob_kzg_proof_batch_filtered(
+            &blobs,
+            &commitments,
+            &proofs,
+            &kzg_settings,
+            &filter
+        )
+        .unwrap());
+        assert!(KzgProof::verify_blob_kzg_proof_batch_filtered(
+            &blobs,
+            &commitments,
+            &proofs,
+            &kzg_settings,
+            &|_: &Bytes48| false
+        )
+        .unwrap());
+
+        // A rejected commitment is never decompressed, so an invalid point is not an error
+        let invalid_commitment = Bytes48::from([0xff; 48]);
+        assert!(!KzgProof::verify_blob_kzg_proof_filtered(
+            &blobs[0],
+            &invalid_commitment,
+            &proofs[0],
+            &kzg_settings,
+            &|commitment: &Bytes48| *commitment == invalid_commitment
+        )
+        .unwrap());
+    }
+
+    #[test]
+    pub fn test_verify_blob_kzg_proof_batch_strict() {
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+
+        assert!(
+            KzgProof::verify_blob_kzg_proof_batch(vec![], vec![], vec![], &kzg_settings).unwrap()
+        );
+        assert!(matches!(
+            KzgProof::verify_blob_kzg_proof_batch_strict(vec![], vec![], vec![], &kzg_settings),
+            Err(KzgError::BadArgs(_))
+        ));
+
+        let data = include_str!("../tests/verify_blob_kzg_proof/verify_blob_kzg_proof_case_correct_proof_fb324bc819407148/data.yaml");
+        let test: Test<BlobInput> = serde_yaml::from_str(data).unwrap();
+        let blob = test.input.get_blob().unwrap();
+        let commitment = test.input.get_commitment().unwrap();
+        let proof = test.input.get_proof().unwrap();
+
+        assert!(KzgProof::verify_blob_kzg_proof_batch_strict(
+            vec![blob.clone()],
+            vec![commitment.clone()],
+            vec![proof],
+            &kzg_settings,
+        )
+        .is_ok());
+        assert!(matches!(
+            KzgProof::verify_blob_kzg_proof_batch_strict(
+                vec![blob],
+                vec![commitment.clone()],
+                vec![commitment],
+                &kzg_settings,
+            ),
+            Err(KzgError::VerificationFailed(_))
+        ));
+    }
+
+    #[test]
+    pub fn test_is_canonical_field_element() {
+        let mut modulus = [0u8; 32];
+        for (chunk, limb) in modulus.chunks_mut(8).zip(MODULUS.iter().rev()) {
+            chunk.copy_from_slice(&limb.to_be_bytes());
+        }
+        let mut modulus_minus_one = modulus;
+        modulus_minus_one[31] -= 1;
+        let mut modulus_plus_one = modulus;
+        modulus_plus_one[31] += 1;
+
+        assert!(is_canonical_field_element(&Bytes32::from([0; 32])));
+        assert!(is_canonical_field_element(&Bytes32::from(
+            modulus_minus_one
+        )));
+        assert_eq!(
+            safe_scalar_affine_from_bytes(&Bytes32::from(modulus_minus_one)).unwrap(),
+            -Scalar::one()
+        );
+        for bytes in [modulus, modulus_plus_one, [0xff; 32]] {
+            assert!(!is_canonical_field_element(&Bytes32::from(bytes)));
+            assert!(safe_scalar_affine_from_bytes(&Bytes32::from(bytes)).is_err());
+        }
+
+        // Hash outputs are reduced instead
+        assert_eq!(scalar_from_bytes_unchecked(modulus), Scalar::zero());
+        assert_eq!(scalar_from_bytes_unchecked(modulus_plus_one), Scalar::one());
+    }
+
+    #[test]
+    pub fn test_scalar_from_bytes_unchecked_reduces() {
+        let canonical = |hex: &str| {
+            let mut bytes = Bytes32::from_hex(hex).unwrap().as_slice().to_vec();
+            bytes.reverse();
+            Scalar::from_bytes(&bytes.try_into().unwrap()).unwrap()
+        };
+
+        // MODULUS + 5
+        let bytes =
+            hex_to_bytes("73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000006")
+                .unwrap();
+        assert_eq!(
+            scalar_from_bytes_unchecked(bytes.try_into().unwrap()),
+            Scalar::from(5)
+        );
+
+        // 2^256 - 1, which is above 2 * MODULUS
+        let expected =
+            canonical("0x1824b159acc5056f998c4fefecbc4ff55884b7fa0003480200000001fffffffd");
+        assert_eq!(scalar_from_bytes_unchecked([0xff; 32]), expected);
+
+        // Canonical values are left alone
+        assert_eq!(scalar_from_bytes_unchecked([0; 32]), Scalar::zero());
+    }
+
+    #[test]
+    pub fn test_verify_blob_kzg_proof_batch_detailed() {
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+
+        let mut blobs = vec![];
+        let mut commitments = vec![];
+        let mut proofs = vec![];
+        for (_test_file, data) in VERIFY_BLOB_KZG_PROOF_TESTS {
+            let test: Test<BlobInput> = serde_yaml::from_str(data).unwrap();
+            if test.get_output() != Some(true) || blobs.len() == 2 {
+                continue;
+            }
+            blobs.push(test.input.get_blob().unwrap());
+            commitments.push(test.input.get_commitment().unwrap());
+            proofs.push(test.input.get_proof().unwrap());
+        }
+
+        let results = KzgProof::verify_blob_kzg_proof_batch_detailed(
+            &blobs,
+            &commitments,
+            &proofs,
+            &kzg_settings,
+        )
+        .unwrap();
+        assert_eq!(results, vec![true, true]);
+
+        // The first blob with the second blob's proof
+        blobs.push(blobs[0].clone());
+        commitments.push(commitments[0].clone());
+        proofs.push(proofs[1].clone());
+        let results = KzgProof::verify_blob_kzg_proof_batch_detailed(
+            &blobs,
+            &commitments,
+            &proofs,
+            &kzg_settings,
+        )
+        .unwrap();
+        assert_eq!(results, vec![true, true, false]);
+
+        // Reversed, so the invalid input comes first
+        fn reversed<T: Clone>(values: &[T]) -> Vec<T> {
+            values.iter().rev().cloned().collect()
+        }
+        let results = KzgProof::verify_blob_kzg_proof_batch_permuted(
+            &reversed(&blobs),
+            &reversed(&commitments),
+            &reversed(&proofs),
+            &[2, 1, 0],
+            &kzg_settings,
+        )
+        .unwrap();
+        assert_eq!(results, vec![true, true, false]);
+
+        for original_indices in [&[0, 1][..], &[0, 1, 1], &[0, 1, 3]] {
+            assert!(matches!(
+                KzgProof::verify_blob_kzg_proof_batch_permuted(
+                    &blobs,
+                    &commitments,
+                    &proofs,
+                    original_indices,
+                    &kzg_settings,
+                ),
+                Err(KzgError::BadArgs(_))
+            ));
+        }
+
+        assert!(
+            KzgProof::verify_blob_kzg_proof_batch_detailed(&[], &[], &[], &kzg_settings)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    pub fn test_compute_challenges_and_evaluate_polynomial() {
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+
+        let mut blobs = vec![];
+        let mut commitments = vec![];
+        for (_test_file, data) in VERIFY_BLOB_KZG_PROOF_BATCH_TESTS {
+            let test: Test<BlobBatchInput> = serde_yaml::from_str(data).unwrap();
+            if test.get_output().is_none() {
+                continue;
+            }
+            blobs.push(test.input.get_blobs().unwrap());
+            let commitment = test.input.get_commitments().unwrap();
+            commitments.push(safe_g1_affine_from_bytes(&commitment).unwrap());
+        }
+
+        // One blob at a time, in order, matches the (possibly parallel) batch computation
+        let expected: (Vec<_>, Vec<_>) = blobs
+            .iter()
+            .zip(&commitments)
+            .map(|(blob, commitment)| {
+                compute_challenge_and_evaluate_polynomial(blob, commitment, &kzg_settings).unwrap()
+            })
+            .unzip();
+        let evaluations =
+            compute_challenges_and_evaluate_polynomial(&blobs, &commitments, &kzg_settings)
+                .unwrap();
+        assert_eq!(evaluations, expected);
+    }
+
+    #[test]
+    pub fn test_batch_transcript_header() {
+        let header = batch_transcript_header(0x0102);
+        assert_eq!(&header[..16], RANDOM_CHALLENGE_KZG_BATCH_DOMAIN.as_bytes());
+        assert_eq!(header[16..24], [0, 0, 0, 0, 0, 0, 0x10, 0]);
+        assert_eq!(header[24..32], [0, 0, 0, 0, 0, 0, 0x01, 0x02]);
+
+        // The same bytes as a 32-bit `usize` would give
+        let n = u32::MAX as usize;
+        assert_eq!(
+            batch_transcript_header(n)[24..32],
+            [0, 0, 0, 0, 0xff, 0xff, 0xff, 0xff]
+        );
+    }
+
     #[test]
     pub fn test_compute_challenge() {
         let data = include_str!("../tests/verify_blob_kzg_proof/verify_blob_kzg_proof_case_correct_proof_fb324bc819407148/data.yaml");
@@ -742,7 +3034,75 @@ pub mod tests {
         assert_eq!(
             format!("{evaluation_challenge}"),
             "0x4f00eef944a21cb9f3ac3390702621e4bbf1198767c43c0fb9c8e9923bfbb31a"
+        );
+
+        let (digest, challenge) = compute_challenge_with_digest(&blob, &commitment).unwrap();
+        assert_eq!(challenge, evaluation_challenge);
+        assert_eq!(
+            scalar_from_bytes_unchecked(digest.clone().into()),
+            evaluation_challenge
+        );
+        let mut hasher = challenge_transcript();
+        hasher.update(blob.as_slice());
+        hasher.update(commitment.to_compressed());
+        assert_eq!(digest.as_slice(), hasher.finalize().as_slice());
+    }
+
+    #[test]
+    pub fn test_verify_blob_kzg_proof_with_challenge() {
+        let data = include_str!("../tests/verify_blob_kzg_proof/verify_blob_kzg_proof_case_correct_proof_fb324bc819407148/data.yaml");
+
+        let test: Test<BlobInput> = serde_yaml::from_str(data).unwrap();
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+        let blob = test.input.get_blob().unwrap();
+        let commitment = test.input.get_commitment().unwrap();
+        let proof = test.input.get_proof().unwrap();
+
+        let (valid, z, y) = KzgProof::verify_blob_kzg_proof_with_challenge(
+            &blob,
+            &commitment,
+            &proof,
+            &kzg_settings,
         )
+        .unwrap();
+        assert!(valid);
+        assert_eq!(
+            format!("{z}"),
+            "0x4f00eef944a21cb9f3ac3390702621e4bbf1198767c43c0fb9c8e9923bfbb31a"
+        );
+        assert_eq!(
+            y,
+            evaluate_polynomial_in_evaluation_form(
+                &blob.as_polynomial().unwrap(),
+                z,
+                &kzg_settings
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    pub fn test_evaluate_polynomial_monomial() {
+        let data = include_str!("../tests/verify_blob_kzg_proof/verify_blob_kzg_proof_case_correct_proof_19b3f3f8c98ea31e/data.yaml");
+
+        let test: Test<BlobInput> = serde_yaml::from_str(data).unwrap();
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+        let polynomial = test.input.get_blob().unwrap().as_polynomial().unwrap();
+        let x = Scalar::from(0x1234_5678_9abc_def0u64);
+
+        let coefficients = lagrange_to_monomial(&polynomial, &kzg_settings).unwrap();
+        assert_eq!(
+            evaluate_polynomial_monomial(&coefficients, x),
+            evaluate_polynomial_in_evaluation_form(&polynomial, x, &kzg_settings).unwrap()
+        );
+
+        // 1 + 2x + 3x^2
+        let coefficients = [1, 2, 3].map(Scalar::from);
+        assert_eq!(
+            evaluate_polynomial_monomial(&coefficients, Scalar::from(5)),
+            Scalar::from(86)
+        );
+        assert!(lagrange_to_monomial(&polynomial[..3], &kzg_settings).is_err());
     }
 
     #[test]
@@ -760,9 +3120,12 @@ pub mod tests {
                 .into(),
         );
 
-        let y =
-            evaluate_polynomial_in_evaluation_form(polynomial, evaluation_challenge, &kzg_settings)
-                .unwrap();
+        let y = evaluate_polynomial_in_evaluation_form(
+            &polynomial,
+            evaluation_challenge,
+            &kzg_settings,
+        )
+        .unwrap();
 
         assert_eq!(
             format!("{y}"),
@@ -1,18 +1,28 @@
-#![cfg_attr(not(test), no_std)]
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
 #[macro_use]
 extern crate alloc;
 
+pub mod ceremony;
 pub mod consts;
 pub mod dtypes;
 pub mod enums;
+pub mod fft;
+pub mod fixed_base;
 pub mod kzg_proof;
+pub mod msm;
 pub mod pairings;
+pub mod point_evaluation;
+#[cfg(feature = "serde")]
+pub mod serde_hex;
+pub mod streaming;
 pub mod trusted_setup;
+pub mod utils;
 
 pub use consts::*;
 pub use dtypes::*;
 pub use kzg_proof::KzgProof;
 pub use pairings::pairings_verify;
+pub use point_evaluation::PointEvaluation;
 pub use trusted_setup::*;
 
 pub use enums::KzgError;
@@ -0,0 +1,182 @@
+use alloc::vec::Vec;
+use bls12_381::Scalar;
+use group::Group;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Inputs of at most this many points skip the bucket method, whose per-window setup outweighs
+/// a handful of double-and-add multiplications.
+pub const MSM_NAIVE_THRESHOLD: usize = 4;
+
+/// Bits in the canonical encoding of a scalar.
+const SCALAR_BITS: usize = 255;
+
+/// Computes `sum(points[i] * scalars[i])`, falling back to naive summation for small inputs.
+/// Works for any group over the BLS12-381 scalar field, e.g. `G1Projective` or `G2Projective`.
+///
+/// Every MSM in the crate goes through this function rather than `G1Projective::msm_variable_base`
+/// from `bls12_381`, so commitments, proofs and batch verification share one implementation.
+pub fn msm_variable_base<G: Group<Scalar = Scalar>>(points: &[G], scalars: &[Scalar]) -> G {
+    if points.len() <= MSM_NAIVE_THRESHOLD {
+        return msm_naive(points, scalars);
+    }
+    msm_pippenger(points, scalars)
+}
+
+/// The window size, in bits, that Pippenger's method uses for `n` points: roughly `ln(n) + 2`,
+/// which balances the `2^c` buckets per window against the `255 / c` windows.
+pub fn optimal_window_size(n: usize) -> usize {
+    if n < 32 {
+        3
+    } else {
+        // ln(n) ~= log2(n) * 0.69
+        (n.ilog2() as usize * 69) / 100 + 2
+    }
+}
+
+/// Pippenger's bucket method. Each window is summed independently, across threads with the
+/// `parallel` feature, then the window sums are combined with doublings.
+pub fn msm_pippenger<G: Group<Scalar = Scalar>>(points: &[G], scalars: &[Scalar]) -> G {
+    let c = optimal_window_size(points.len());
+    let scalars = scalars.iter().map(Scalar::to_bytes).collect::<Vec<_>>();
+    let num_windows = SCALAR_BITS.div_ceil(c);
+
+    let window_sum = |window: usize| {
+        let mut buckets = vec![G::identity(); (1 << c) - 1];
+        for (point, scalar) in points.iter().zip(&scalars) {
+            let digit = window_digit(scalar, window * c, c);
+            if digit != 0 {
+                buckets[digit - 1] += point;
+            }
+        }
+
+        // sum(digit * bucket[digit]) as a sum of running sums, highest bucket first
+        let mut running_sum = G::identity();
+        let mut sum = G::identity();
+        for bucket in buckets.iter().rev() {
+            running_sum += bucket;
+            sum += running_sum;
+        }
+        sum
+    };
+
+    #[cfg(feature = "parallel")]
+    let window_sums = (0..num_windows)
+        .into_par_iter()
+        .map(window_sum)
+        .collect::<Vec<_>>();
+    #[cfg(not(feature = "parallel"))]
+    let window_sums = (0..num_windows).map(window_sum).collect::<Vec<_>>();
+
+    window_sums
+        .iter()
+        .rev()
+        .fold(G::identity(), |acc, window_sum| {
+            (0..c).fold(acc, |acc, _| acc.double()) + window_sum
+        })
+}
+
+/// Reads the `c` bits of the little-endian scalar starting at bit `start`.
+fn window_digit(scalar: &[u8; 32], start: usize, c: usize) -> usize {
+    (0..c)
+        .map(|i| start + i)
+        .take_while(|&bit| bit < SCALAR_BITS)
+        .filter(|&bit| (scalar[bit / 8] >> (bit % 8)) & 1 == 1)
+        .fold(0, |digit, bit| digit | 1 << (bit - start))
+}
+
+fn msm_naive<G: Group<Scalar = Scalar>>(points: &[G], scalars: &[Scalar]) -> G {
+    points
+        .iter()
+        .zip(scalars.iter())
+        .fold(G::identity(), |acc, (point, scalar)| acc + *point * scalar)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kzg_proof::compute_powers;
+    use bls12_381::{G1Projective, G2Projective};
+
+    /// Multiplies by the scalar's bits from the most significant down.
+    fn double_and_add(point: &G1Projective, scalar: &Scalar) -> G1Projective {
+        let bytes = scalar.to_bytes();
+        (0..SCALAR_BITS)
+            .rev()
+            .fold(G1Projective::identity(), |acc, bit| {
+                let acc = acc.double();
+                if (bytes[bit / 8] >> (bit % 8)) & 1 == 1 {
+                    acc + point
+                } else {
+                    acc
+                }
+            })
+    }
+
+    #[test]
+    fn test_msm_matches_bls12_381() {
+        for n in (0..=MSM_NAIVE_THRESHOLD + 2).chain([33, 257]) {
+            let points = (0..n)
+                .map(|i| G1Projective::generator() * Scalar::from(i as u64 + 7))
+                .collect::<Vec<_>>();
+            let scalars = compute_powers(&Scalar::from(0x1234_5678_9abc_def0u64), n);
+
+            assert_eq!(
+                msm_variable_base(&points, &scalars),
+                G1Projective::msm_variable_base(&points, &scalars)
+            );
+        }
+    }
+
+    #[test]
+    fn test_pippenger_matches_double_and_add() {
+        for n in [1, 2, 31, 32, 1000] {
+            let points = compute_powers(&Scalar::from(3), n)
+                .iter()
+                .map(|scalar| G1Projective::generator() * scalar)
+                .collect::<Vec<_>>();
+            // Powers of a large base, so the scalars use all of their bits
+            let scalars = compute_powers(&-Scalar::from(0x1234_5678_9abc_def0u64), n);
+
+            let expected = points
+                .iter()
+                .zip(&scalars)
+                .fold(G1Projective::identity(), |acc, (point, scalar)| {
+                    acc + double_and_add(point, scalar)
+                });
+            assert_eq!(msm_pippenger(&points, &scalars), expected);
+            assert_eq!(msm_variable_base(&points, &scalars), expected);
+        }
+    }
+
+    #[test]
+    fn test_optimal_window_size() {
+        assert_eq!(optimal_window_size(0), 3);
+        assert_eq!(optimal_window_size(31), 3);
+        assert_eq!(optimal_window_size(4096), 10);
+
+        let sizes = (0..20)
+            .map(|i| optimal_window_size(1 << i))
+            .collect::<Vec<_>>();
+        assert!(sizes.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+
+    #[test]
+    fn test_g2_msm_matches_naive() {
+        for n in [1, 3, 40] {
+            let points = compute_powers(&Scalar::from(5), n)
+                .iter()
+                .map(|scalar| G2Projective::generator() * scalar)
+                .collect::<Vec<_>>();
+            let scalars = compute_powers(&-Scalar::from(0x0fed_cba9_8765_4321u64), n);
+
+            let expected = points
+                .iter()
+                .zip(&scalars)
+                .fold(G2Projective::identity(), |acc, (point, scalar)| {
+                    acc + point * scalar
+                });
+            assert_eq!(msm_variable_base(&points, &scalars), expected);
+        }
+    }
+}
@@ -1,9 +1,91 @@
 #[allow(unused_imports)]
 use bls12_381::{multi_miller_loop, G1Affine, G2Affine, G2Prepared, Gt, Scalar};
 
-/// Verifies the pairing of two G1 and two G2 points are equivalent using the multi-miller loop
+/// Verifies the pairing of two G1 and two G2 points are equivalent using the multi-miller loop.
+///
+/// This is the crate's only pairing check: it shares one final exponentiation between both
+/// pairings, where `e(a1, a2) == e(b1, b2)` computed naively needs two.
 pub fn pairings_verify(a1: G1Affine, a2: G2Affine, b1: G1Affine, b2: G2Affine) -> bool {
     multi_miller_loop(&[(&-a1, &G2Prepared::from(a2)), (&b1, &G2Prepared::from(b2))])
         .final_exponentiation()
         == Gt::identity()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kzg_proof::tests::{Input, Test};
+    use crate::test_files::VERIFY_KZG_PROOF_TESTS;
+    use crate::{kzg_proof::compute_powers, KzgProof, KzgSettings};
+    use bls12_381::{pairing, G1Projective, G2Projective};
+
+    /// The two-pairing form of the check, with a final exponentiation per pairing.
+    fn pairings_verify_reference(a1: G1Affine, a2: G2Affine, b1: G1Affine, b2: G2Affine) -> bool {
+        pairing(&a1, &a2) == pairing(&b1, &b2)
+    }
+
+    #[test]
+    fn test_pairings_verify_matches_reference_on_vectors() {
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+
+        for (_test_file, data) in VERIFY_KZG_PROOF_TESTS {
+            let test: Test<Input> = serde_yaml::from_str(data).unwrap();
+            let (Ok(commitment), Ok(z), Ok(y), Ok(proof)) = (
+                test.input.get_commitment(),
+                test.input.get_z(),
+                test.input.get_y(),
+                test.input.get_proof(),
+            ) else {
+                continue;
+            };
+            let Ok(inputs) = KzgProof::verify_kzg_proof_pairing_inputs(
+                &commitment,
+                &z,
+                &y,
+                &proof,
+                &kzg_settings,
+            ) else {
+                continue;
+            };
+
+            assert_eq!(
+                pairings_verify(
+                    inputs.p_minus_y,
+                    inputs.generator,
+                    inputs.proof,
+                    inputs.x_minus_z
+                ),
+                pairings_verify_reference(
+                    inputs.p_minus_y,
+                    inputs.generator,
+                    inputs.proof,
+                    inputs.x_minus_z
+                ),
+            );
+        }
+    }
+
+    #[test]
+    fn test_pairings_verify_matches_reference_on_random_inputs() {
+        let scalars = compute_powers(&Scalar::from(0x0123_4567_89ab_cdefu64), 16);
+
+        for window in scalars.windows(2) {
+            let (a, b) = (window[0], window[1]);
+            let a1 = G1Affine::from(G1Projective::generator() * a);
+            let a2 = G2Affine::from(G2Projective::generator() * b);
+
+            // e(a * G1, b * G2) == e(b * G1, a * G2), but not e(G1, a * b * G2 + G2)
+            let equal_b1 = G1Affine::from(G1Projective::generator() * b);
+            let equal_b2 = G2Affine::from(G2Projective::generator() * a);
+            let unequal_b2 = G2Affine::from(G2Projective::generator() * (a * b + Scalar::one()));
+
+            for (b1, b2, expected) in [
+                (equal_b1, equal_b2, true),
+                (G1Affine::generator(), unequal_b2, false),
+            ] {
+                assert_eq!(pairings_verify(a1, a2, b1, b2), expected);
+                assert_eq!(pairings_verify_reference(a1, a2, b1, b2), expected);
+            }
+        }
+    }
+}
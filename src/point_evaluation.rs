@@ -0,0 +1,145 @@
+use crate::enums::KzgError;
+use crate::kzg_proof::KzgProof;
+use crate::trusted_setup::KzgSettings;
+use crate::{
+    dtypes::*, BYTES_PER_COMMITMENT, BYTES_PER_FIELD_ELEMENT, BYTES_PER_POINT_EVALUATION_INPUT,
+    VERSIONED_HASH_VERSION_KZG,
+};
+
+use alloc::string::ToString;
+use sha2::{Digest, Sha256};
+
+/// The inputs of the EIP-4844 point evaluation precompile, in the order of its 192-byte layout.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PointEvaluation {
+    pub versioned_hash: Bytes32,
+    pub z: Bytes32,
+    pub y: Bytes32,
+    pub commitment: Bytes48,
+    pub proof: Bytes48,
+}
+
+impl PointEvaluation {
+    pub fn from_precompile_bytes(
+        input: &[u8; BYTES_PER_POINT_EVALUATION_INPUT],
+    ) -> Result<Self, KzgError> {
+        let (versioned_hash, rest) = input.split_at(32);
+        let (z, rest) = rest.split_at(BYTES_PER_FIELD_ELEMENT);
+        let (y, rest) = rest.split_at(BYTES_PER_FIELD_ELEMENT);
+        let (commitment, proof) = rest.split_at(BYTES_PER_COMMITMENT);
+
+        Ok(Self {
+            versioned_hash: Bytes32::from_slice(versioned_hash)?,
+            z: Bytes32::from_slice(z)?,
+            y: Bytes32::from_slice(y)?,
+            commitment: Bytes48::from_slice(commitment)?,
+            proof: Bytes48::from_slice(proof)?,
+        })
+    }
+
+    pub fn to_precompile_bytes(&self) -> [u8; BYTES_PER_POINT_EVALUATION_INPUT] {
+        let mut bytes = [0; BYTES_PER_POINT_EVALUATION_INPUT];
+        let fields = [
+            self.versioned_hash.as_slice(),
+            self.z.as_slice(),
+            self.y.as_slice(),
+            self.commitment.as_slice(),
+            self.proof.as_slice(),
+        ];
+        let mut offset = 0;
+        for field in fields {
+            bytes[offset..offset + field.len()].copy_from_slice(field);
+            offset += field.len();
+        }
+        bytes
+    }
+
+    /// Checks that the versioned hash commits to the commitment, returning `BadArgs` otherwise,
+    /// then verifies the proof that the committed polynomial evaluates to `y` at `z`.
+    pub fn verify(&self, kzg_settings: &KzgSettings) -> Result<bool, KzgError> {
+        if kzg_to_versioned_hash(&self.commitment) != self.versioned_hash {
+            return Err(KzgError::BadArgs(
+                "The versioned hash does not match the commitment".to_string(),
+            ));
+        }
+
+        KzgProof::verify_kzg_proof(
+            &self.commitment,
+            &self.z,
+            &self.y,
+            &self.proof,
+            kzg_settings,
+        )
+    }
+}
+
+/// The versioned hash of a commitment: its SHA-256 with the first byte replaced by the version.
+pub fn kzg_to_versioned_hash(commitment: &Bytes48) -> Bytes32 {
+    let mut hash: [u8; 32] = Sha256::digest(commitment.as_slice()).into();
+    hash[0] = VERSIONED_HASH_VERSION_KZG;
+    Bytes32::from(hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kzg_proof::tests::{Input, Test};
+    use crate::test_files::VERIFY_KZG_PROOF_TESTS;
+
+    #[test]
+    fn test_point_evaluation() {
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+
+        for (_test_file, data) in VERIFY_KZG_PROOF_TESTS {
+            let test: Test<Input> = serde_yaml::from_str(data).unwrap();
+            let (Ok(commitment), Ok(z), Ok(y), Ok(proof)) = (
+                test.input.get_commitment(),
+                test.input.get_z(),
+                test.input.get_y(),
+                test.input.get_proof(),
+            ) else {
+                continue;
+            };
+            let Some(expected) = test.get_output() else {
+                continue;
+            };
+
+            let point_evaluation = PointEvaluation {
+                versioned_hash: kzg_to_versioned_hash(&commitment),
+                z,
+                y,
+                commitment,
+                proof,
+            };
+            let bytes = point_evaluation.to_precompile_bytes();
+            assert_eq!(bytes[0], VERSIONED_HASH_VERSION_KZG);
+
+            let parsed = PointEvaluation::from_precompile_bytes(&bytes).unwrap();
+            assert_eq!(parsed, point_evaluation);
+            assert_eq!(parsed.verify(&kzg_settings).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_point_evaluation_versioned_hash_mismatch() {
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+        let data = VERIFY_KZG_PROOF_TESTS[0].1;
+        let test: Test<Input> = serde_yaml::from_str(data).unwrap();
+        let commitment = test.input.get_commitment().unwrap();
+
+        let mut versioned_hash: [u8; 32] = kzg_to_versioned_hash(&commitment).into();
+        versioned_hash[31] ^= 1;
+        let point_evaluation = PointEvaluation {
+            versioned_hash: Bytes32::from(versioned_hash),
+            z: test.input.get_z().unwrap(),
+            y: test.input.get_y().unwrap(),
+            commitment,
+            proof: test.input.get_proof().unwrap(),
+        };
+        assert!(matches!(
+            point_evaluation.verify(&kzg_settings),
+            Err(KzgError::BadArgs(_))
+        ));
+    }
+}
@@ -0,0 +1,28 @@
+//! (De)serializes byte types as `0x`-prefixed hex strings, the encoding of the EIP-4844 JSON and
+//! YAML fixtures. The byte types of the crate serialize this way already; the module is for
+//! fields of other types, with `#[serde(with = "kzg_rs::serde_hex")]`.
+
+use crate::enums::KzgError;
+use crate::utils::hex_to_bytes;
+
+use alloc::string::String;
+use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+pub fn serialize<T: AsRef<[u8]>, S: Serializer>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&format!("0x{}", hex::encode(value.as_ref())))
+}
+
+/// Parses a hex string, with or without the `0x` prefix, rejecting any length but the type's.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: for<'a> TryFrom<&'a [u8], Error = KzgError>,
+    D: Deserializer<'de>,
+{
+    let hex_str = String::deserialize(deserializer)?;
+    hex_to_bytes(&hex_str)
+        .and_then(|bytes| T::try_from(bytes.as_slice()))
+        .map_err(D::Error::custom)
+}
@@ -0,0 +1,328 @@
+use crate::{
+    enums::KzgError,
+    fixed_base::g1_generator_table,
+    kzg_proof::{
+        challenge_transcript, evaluate_polynomial_in_evaluation_form, safe_g1_affine_from_bytes,
+        safe_scalar_affine_from_bytes, scalar_from_bytes_unchecked, verify_kzg_proof_impl,
+    },
+    trusted_setup::KzgSettings,
+    Bytes32, Bytes48, BYTES_PER_BLOB, BYTES_PER_FIELD_ELEMENT, NUM_FIELD_ELEMENTS_PER_BLOB,
+};
+
+use alloc::{string::ToString, vec::Vec};
+use bls12_381::{multi_miller_loop, G1Affine, G2Affine, G2Prepared, Gt, Scalar};
+use sha2::{Digest, Sha256};
+
+/// Verifies a blob proof while the blob arrives in chunks of arbitrary size. The challenge
+/// transcript is hashed and the field elements parsed as the bytes come in, so only the parsed
+/// polynomial is kept rather than the raw blob; the result is the same as
+/// [`crate::KzgProof::verify_blob_kzg_proof`]. Once an update fails, every later update and
+/// [`StreamingBlobVerifier::finalize`] fail too.
+pub struct StreamingBlobVerifier<'a> {
+    kzg_settings: &'a KzgSettings,
+    commitment: G1Affine,
+    proof: G1Affine,
+    hasher: Sha256,
+    polynomial: Vec<Scalar>,
+    /// The bytes of a field element that was split across chunks.
+    pending: [u8; BYTES_PER_FIELD_ELEMENT],
+    pending_len: usize,
+    /// Set when an update failed part way, leaving the transcript and polynomial out of step.
+    poisoned: bool,
+}
+
+impl<'a> StreamingBlobVerifier<'a> {
+    pub fn new(
+        commitment_bytes: &Bytes48,
+        proof_bytes: &Bytes48,
+        kzg_settings: &'a KzgSettings,
+    ) -> Result<Self, KzgError> {
+        Ok(Self {
+            kzg_settings,
+            commitment: safe_g1_affine_from_bytes(commitment_bytes)?,
+            proof: safe_g1_affine_from_bytes(proof_bytes)?,
+            hasher: challenge_transcript(),
+            polynomial: Vec::with_capacity(NUM_FIELD_ELEMENTS_PER_BLOB),
+            pending: [0; BYTES_PER_FIELD_ELEMENT],
+            pending_len: 0,
+            poisoned: false,
+        })
+    }
+
+    /// The number of blob bytes received so far.
+    pub fn bytes_received(&self) -> usize {
+        self.polynomial.len() * BYTES_PER_FIELD_ELEMENT + self.pending_len
+    }
+
+    /// Feeds the next bytes of the blob.
+    pub fn update(&mut self, bytes: &[u8]) -> Result<(), KzgError> {
+        self.check_poisoned()?;
+        match self.absorb(bytes) {
+            // Only hash the bytes once they all parsed
+            Ok(()) => {
+                self.hasher.update(bytes);
+                Ok(())
+            }
+            Err(e) => {
+                self.poisoned = true;
+                Err(e)
+            }
+        }
+    }
+
+    fn check_poisoned(&self) -> Result<(), KzgError> {
+        if self.poisoned {
+            return Err(KzgError::BadArgs(
+                "An earlier update of the blob failed".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn absorb(&mut self, mut bytes: &[u8]) -> Result<(), KzgError> {
+        if self.bytes_received() + bytes.len() > BYTES_PER_BLOB {
+            return Err(KzgError::InvalidBytesLength(format!(
+                "The blob is {} bytes, but {} were supplied",
+                BYTES_PER_BLOB,
+                self.bytes_received() + bytes.len()
+            )));
+        }
+
+        // Complete the field element left over from the previous chunk
+        if self.pending_len > 0 {
+            let n = bytes.len().min(BYTES_PER_FIELD_ELEMENT - self.pending_len);
+            self.pending[self.pending_len..self.pending_len + n].copy_from_slice(&bytes[..n]);
+            self.pending_len += n;
+            bytes = &bytes[n..];
+
+            if self.pending_len < BYTES_PER_FIELD_ELEMENT {
+                return Ok(());
+            }
+            let element = Bytes32::from_slice(&self.pending)?;
+            self.polynomial
+                .push(safe_scalar_affine_from_bytes(&element)?);
+            self.pending_len = 0;
+        }
+
+        let mut chunks = bytes.chunks_exact(BYTES_PER_FIELD_ELEMENT);
+        for chunk in &mut chunks {
+            let element = Bytes32::from_slice(chunk)?;
+            self.polynomial
+                .push(safe_scalar_affine_from_bytes(&element)?);
+        }
+
+        let remainder = chunks.remainder();
+        self.pending[..remainder.len()].copy_from_slice(remainder);
+        self.pending_len = remainder.len();
+
+        Ok(())
+    }
+
+    /// Checks the proof once the whole blob has been received.
+    pub fn finalize(self) -> Result<bool, KzgError> {
+        self.check_poisoned()?;
+        if self.polynomial.len() != NUM_FIELD_ELEMENTS_PER_BLOB {
+            return Err(KzgError::InvalidBytesLength(
+                "The blob has not been fully received".to_string(),
+            ));
+        }
+
+        let mut hasher = self.hasher;
+        hasher.update(self.commitment.to_compressed());
+        let evaluation_challenge = scalar_from_bytes_unchecked(hasher.finalize().into());
+
+        let y = evaluate_polynomial_in_evaluation_form(
+            &self.polynomial,
+            evaluation_challenge,
+            self.kzg_settings,
+        )?;
+
+        verify_kzg_proof_impl(
+            self.commitment,
+            evaluation_challenge,
+            y,
+            self.proof,
+            self.kzg_settings,
+        )
+    }
+}
+
+/// Verifies many opening proofs of one commitment, e.g. the samples of a data availability
+/// sampler. The commitment is decompressed and both G2 points are prepared once.
+///
+/// The check `e(C - [y]G1, G2) == e(proof, [s - z]G2)` is rearranged to
+/// `e(C - [y]G1 + [z]proof, G2) == e(proof, [s]G2)`, so that neither G2 point depends on `z`.
+pub struct MultiPointVerifier {
+    commitment: G1Affine,
+    generator: G2Prepared,
+    s_g2: G2Prepared,
+}
+
+impl MultiPointVerifier {
+    pub fn new(commitment_bytes: &Bytes48, kzg_settings: &KzgSettings) -> Result<Self, KzgError> {
+        Ok(Self {
+            commitment: safe_g1_affine_from_bytes(commitment_bytes)?,
+            generator: G2Prepared::from(G2Affine::generator()),
+            s_g2: G2Prepared::from(*kzg_settings.g2_secret_generator()?),
+        })
+    }
+
+    /// Verifies that the committed polynomial evaluates to `y` at `z`, like
+    /// [`crate::KzgProof::verify_kzg_proof`].
+    pub fn verify(
+        &self,
+        z_bytes: &Bytes32,
+        y_bytes: &Bytes32,
+        proof_bytes: &Bytes48,
+    ) -> Result<bool, KzgError> {
+        let z = safe_scalar_affine_from_bytes(z_bytes)?;
+        let y = safe_scalar_affine_from_bytes(y_bytes)?;
+        let proof = safe_g1_affine_from_bytes(proof_bytes)?;
+
+        let lhs = G1Affine::from(self.commitment - g1_generator_table().mul(&y) + proof * z);
+        Ok(
+            multi_miller_loop(&[(&-lhs, &self.generator), (&proof, &self.s_g2)])
+                .final_exponentiation()
+                == Gt::identity(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kzg_proof::tests::{BlobInput, Input, Test};
+    use crate::test_files::{VERIFY_BLOB_KZG_PROOF_TESTS, VERIFY_KZG_PROOF_TESTS};
+    use crate::KzgProof;
+
+    fn verify_streaming(
+        blob: &[u8],
+        commitment: &Bytes48,
+        proof: &Bytes48,
+        chunk_size: usize,
+        kzg_settings: &KzgSettings,
+    ) -> Result<bool, KzgError> {
+        let mut verifier = StreamingBlobVerifier::new(commitment, proof, kzg_settings)?;
+        for chunk in blob.chunks(chunk_size) {
+            verifier.update(chunk)?;
+        }
+        verifier.finalize()
+    }
+
+    #[test]
+    fn test_streaming_matches_verify_blob_kzg_proof() {
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+
+        for (_test_file, data) in VERIFY_BLOB_KZG_PROOF_TESTS {
+            let test: Test<BlobInput> = serde_yaml::from_str(data).unwrap();
+            let (Ok(blob), Ok(commitment), Ok(proof)) = (
+                test.input.get_blob(),
+                test.input.get_commitment(),
+                test.input.get_proof(),
+            ) else {
+                continue;
+            };
+
+            let expected =
+                KzgProof::verify_blob_kzg_proof(blob.clone(), &commitment, &proof, &kzg_settings)
+                    .ok();
+            for chunk_size in [BYTES_PER_BLOB, 1000, 32, 7] {
+                let result = verify_streaming(
+                    blob.as_slice(),
+                    &commitment,
+                    &proof,
+                    chunk_size,
+                    &kzg_settings,
+                );
+                assert_eq!(result.ok(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_streaming_rejects_wrong_length() {
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+        let point = Bytes48::from_slice(&G1Affine::identity().to_compressed()).unwrap();
+        let blob = [0u8; BYTES_PER_BLOB + 1];
+
+        let mut verifier = StreamingBlobVerifier::new(&point, &point, &kzg_settings).unwrap();
+        verifier.update(&blob[..BYTES_PER_BLOB - 1]).unwrap();
+        assert!(verifier.update(&blob[..2]).is_err());
+        assert!(verifier.finalize().is_err());
+
+        let mut verifier = StreamingBlobVerifier::new(&point, &point, &kzg_settings).unwrap();
+        verifier.update(&blob[..BYTES_PER_BLOB]).unwrap();
+        assert!(verifier.finalize().unwrap());
+    }
+
+    #[test]
+    fn test_streaming_poisoned_by_failed_update() {
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+        let point = Bytes48::from_slice(&G1Affine::identity().to_compressed()).unwrap();
+        let blob = [0u8; BYTES_PER_BLOB];
+        let mut non_canonical = [0u8; 3 * BYTES_PER_FIELD_ELEMENT];
+        non_canonical[BYTES_PER_FIELD_ELEMENT..2 * BYTES_PER_FIELD_ELEMENT].fill(0xff);
+
+        // The chunk fails part way, after its first element was parsed
+        let mut verifier = StreamingBlobVerifier::new(&point, &point, &kzg_settings).unwrap();
+        assert!(verifier.update(&non_canonical).is_err());
+        assert!(verifier.update(&blob[..BYTES_PER_FIELD_ELEMENT]).is_err());
+        assert!(verifier.finalize().is_err());
+
+        // An all-zero blob verifies against the identity, so only poisoning rejects it here
+        let mut verifier = StreamingBlobVerifier::new(&point, &point, &kzg_settings).unwrap();
+        assert!(verifier
+            .update(&non_canonical[..2 * BYTES_PER_FIELD_ELEMENT])
+            .is_err());
+        assert!(verifier
+            .update(&blob[2 * BYTES_PER_FIELD_ELEMENT..])
+            .is_err());
+        assert!(verifier.finalize().is_err());
+    }
+
+    #[test]
+    fn test_multi_point_verifier_matches_verify_kzg_proof() {
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+
+        for (_test_file, data) in VERIFY_KZG_PROOF_TESTS {
+            let test: Test<Input> = serde_yaml::from_str(data).unwrap();
+            let (Ok(commitment), Ok(z), Ok(y), Ok(proof)) = (
+                test.input.get_commitment(),
+                test.input.get_z(),
+                test.input.get_y(),
+                test.input.get_proof(),
+            ) else {
+                continue;
+            };
+
+            let expected = KzgProof::verify_kzg_proof(&commitment, &z, &y, &proof, &kzg_settings);
+            let result = MultiPointVerifier::new(&commitment, &kzg_settings)
+                .and_then(|verifier| verifier.verify(&z, &y, &proof));
+            assert_eq!(result.ok(), expected.ok());
+        }
+    }
+
+    #[test]
+    fn test_multi_point_verifier_many_points() {
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+        let data = include_str!("../tests/verify_blob_kzg_proof/verify_blob_kzg_proof_case_correct_proof_19b3f3f8c98ea31e/data.yaml");
+        let test: Test<BlobInput> = serde_yaml::from_str(data).unwrap();
+        let blob = test.input.get_blob().unwrap();
+        let commitment = KzgProof::blob_to_kzg_commitment(&blob, &kzg_settings).unwrap();
+
+        let verifier = MultiPointVerifier::new(&commitment, &kzg_settings).unwrap();
+        for i in 1..5u8 {
+            let mut z = [0u8; 32];
+            z[31] = i;
+            let z = Bytes32::from(z);
+            let (proof, y) = KzgProof::compute_kzg_proof(&blob, &z, &kzg_settings).unwrap();
+            assert!(verifier.verify(&z, &y, &proof).unwrap());
+
+            let mut wrong_y = <[u8; 32]>::from(y);
+            wrong_y[31] ^= 1;
+            assert!(!verifier
+                .verify(&z, &Bytes32::from(wrong_y), &proof)
+                .unwrap());
+        }
+    }
+}
@@ -1,12 +1,26 @@
-use crate::{enums::KzgError, NUM_G1_POINTS, NUM_ROOTS_OF_UNITY};
+use crate::{
+    enums::KzgError,
+    kzg_proof::{safe_scalar_affine_from_bytes, scalar_to_bytes32},
+    msm::msm_variable_base,
+    pairings::pairings_verify,
+    utils::{
+        bit_reversal_permutation, compute_roots_of_unity, decompress_g1_point,
+        is_trusted_setup_in_lagrange_form, parse_trusted_setup_with,
+    },
+    Bytes32, BYTES_PER_COMPRESSED_KZG_SETTINGS, BYTES_PER_FIELD_ELEMENT, BYTES_PER_G1_POINT,
+    BYTES_PER_G2_POINT, MAINNET_G1_POINT_0, MAINNET_G2_POINT_0, MAINNET_G2_POINT_1,
+    NUM_FIELD_ELEMENTS_PER_BLOB, NUM_G1_POINTS, NUM_G2_POINTS, NUM_ROOTS_OF_UNITY,
+};
 
-use alloc::sync::Arc;
-use bls12_381::{G1Affine, G2Affine, Scalar};
+use alloc::{borrow::Cow, string::ToString, sync::Arc, vec::Vec};
+use bls12_381::{G1Affine, G1Projective, G2Affine, Scalar};
 use core::{
     hash::{Hash, Hasher},
     mem::transmute,
-    slice,
+    ptr, slice,
 };
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 use spin::Once;
 
 pub fn get_roots_of_unity() -> &'static [Scalar] {
@@ -29,26 +43,48 @@ pub fn get_g2_points() -> &'static [G2Affine] {
     static G2_POINTS: Once<&'static [G2Affine]> = Once::new();
     G2_POINTS.call_once(|| {
         let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/g2.bin"));
-        unsafe { transmute(slice::from_raw_parts(bytes.as_ptr(), NUM_G1_POINTS)) }
+        debug_assert_eq!(
+            bytes.len(),
+            NUM_G2_POINTS * core::mem::size_of::<G2Affine>()
+        );
+        unsafe { transmute(slice::from_raw_parts(bytes.as_ptr(), NUM_G2_POINTS)) }
     })
 }
 
 pub fn get_kzg_settings() -> KzgSettings {
     KzgSettings {
-        roots_of_unity: get_roots_of_unity(),
-        g1_points: get_g1_points(),
-        g2_points: get_g2_points(),
+        roots_of_unity: Cow::Borrowed(get_roots_of_unity()),
+        g1_points: Cow::Borrowed(get_g1_points()),
+        g2_points: Cow::Borrowed(get_g2_points()),
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+/// The baked-in setup borrows the static tables generated by `build.rs`; setups loaded at
+/// runtime own their points.
+#[derive(Debug, Clone, Eq)]
 #[repr(C, align(4))]
 pub struct KzgSettings {
-    pub roots_of_unity: &'static [Scalar],
-    pub g1_points: &'static [G1Affine],
-    pub g2_points: &'static [G2Affine],
+    pub roots_of_unity: Cow<'static, [Scalar]>,
+    pub g1_points: Cow<'static, [G1Affine]>,
+    pub g2_points: Cow<'static, [G2Affine]>,
+}
+
+/// Settings whose tables are the same slices, such as two copies of the baked-in setup, are equal
+/// without looking at the points. Otherwise the points are compared by content.
+impl PartialEq for KzgSettings {
+    fn eq(&self, other: &Self) -> bool {
+        fn same_or_equal<T: PartialEq>(a: &[T], b: &[T]) -> bool {
+            ptr::eq(a, b) || a == b
+        }
+
+        same_or_equal(&self.roots_of_unity, &other.roots_of_unity)
+            && same_or_equal(&self.g1_points, &other.g1_points)
+            && same_or_equal(&self.g2_points, &other.g2_points)
+    }
 }
 
+/// The baked-in setup, or a custom one shared behind an `Arc`. A custom setup owns its points
+/// and is freed with its last handle, so loading and dropping setups leaks nothing.
 #[derive(Debug, Clone, Default, Eq)]
 pub enum EnvKzgSettings {
     #[default]
@@ -76,7 +112,43 @@ impl Hash for EnvKzgSettings {
     }
 }
 
+impl From<KzgSettings> for EnvKzgSettings {
+    fn from(settings: KzgSettings) -> Self {
+        Self::Custom(Arc::new(settings))
+    }
+}
+
+/// The environment variable that [`EnvKzgSettings::load_from_env`] reads the path of a trusted
+/// setup file from.
+#[cfg(feature = "std")]
+pub const KZG_TRUSTED_SETUP_ENV: &str = "KZG_TRUSTED_SETUP";
+
 impl EnvKzgSettings {
+    /// Loads the setup file named by [`KZG_TRUSTED_SETUP_ENV`], in the format of
+    /// `trusted_setup.txt`, or returns [`EnvKzgSettings::Default`] if the variable is not set.
+    #[cfg(feature = "std")]
+    pub fn load_from_env() -> Result<Self, KzgError> {
+        let path = std::env::var_os(KZG_TRUSTED_SETUP_ENV);
+        Self::load_from_path(path.as_deref().map(std::path::Path::new))
+    }
+
+    /// Loads the setup file at `path`, in the format of `trusted_setup.txt`, or returns
+    /// [`EnvKzgSettings::Default`] for `None`.
+    #[cfg(feature = "std")]
+    pub fn load_from_path(path: Option<&std::path::Path>) -> Result<Self, KzgError> {
+        let Some(path) = path else {
+            return Ok(Self::Default);
+        };
+        let file = std::fs::File::open(path).map_err(|e| {
+            KzgError::InvalidTrustedSetup(format!(
+                "Failed to open the trusted setup {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+        KzgSettings::load_trusted_setup_from_reader(std::io::BufReader::new(file)).map(Self::from)
+    }
+
     pub fn get(&self) -> &KzgSettings {
         match self {
             Self::Default => {
@@ -92,7 +164,1209 @@ impl EnvKzgSettings {
 }
 
 impl KzgSettings {
+    /// Returns the baked-in Ethereum mainnet setup. With the `integrity-check` feature, the
+    /// first call also runs [`KzgSettings::verify_mainnet_setup`] on it.
     pub fn load_trusted_setup_file() -> Result<Self, KzgError> {
-        Ok(get_kzg_settings())
+        let settings = get_kzg_settings();
+        #[cfg(feature = "integrity-check")]
+        {
+            static INTEGRITY: Once<Result<(), KzgError>> = Once::new();
+            INTEGRITY
+                .call_once(|| settings.verify_mainnet_setup())
+                .clone()?;
+        }
+        Ok(settings)
+    }
+
+    /// Integrity safeguard against a corrupted setup: checks that this is the Ethereum mainnet
+    /// setup, beyond the form check of [`is_trusted_setup_in_lagrange_form`].
+    ///
+    /// `g1_points[0]`, `g2_points[0]` and `g2_points[1]` must equal their pinned mainnet values,
+    /// and the G1 points must be a Lagrange basis for the tau of `g2_points[1]`. A single
+    /// Lagrange point has no pairing relation of its own, so the check interpolates `x`:
+    /// `sum(w_i * g1_points[i]) = tau * G1`, then checks `e(tau * G1, G2) == e(G1, tau * G2)`.
+    pub fn verify_mainnet_setup(&self) -> Result<(), KzgError> {
+        if self.g1_points.is_empty()
+            || self.g1_points.len() != self.roots_of_unity.len()
+            || self.g2_points.len() < 2
+        {
+            return Err(KzgError::InvalidTrustedSetup(
+                "Unexpected number of points".to_string(),
+            ));
+        }
+
+        let pinned = [
+            (
+                hex::encode(self.g1_points[0].to_compressed()),
+                MAINNET_G1_POINT_0,
+                "g1_points[0]",
+            ),
+            (
+                hex::encode(self.g2_points[0].to_compressed()),
+                MAINNET_G2_POINT_0,
+                "g2_points[0]",
+            ),
+            (
+                hex::encode(self.g2_points[1].to_compressed()),
+                MAINNET_G2_POINT_1,
+                "g2_points[1]",
+            ),
+        ];
+        for (point, expected, name) in pinned {
+            if point != expected {
+                return Err(KzgError::InvalidTrustedSetup(format!(
+                    "{name} does not match the mainnet setup"
+                )));
+            }
+        }
+
+        self.verify_shared_tau()
+    }
+
+    /// Checks `e(sum(w_i * g1_points[i]), g2_points[0]) == e(G1, g2_points[1])`, i.e. that the G1
+    /// points are a Lagrange basis for the tau of the G2 points. Needs two G2 points and as many
+    /// G1 points as roots of unity.
+    fn verify_shared_tau(&self) -> Result<(), KzgError> {
+        let g1_points = self
+            .g1_points
+            .iter()
+            .map(G1Projective::from)
+            .collect::<Vec<_>>();
+        let tau_g1 = msm_variable_base(&g1_points, &self.roots_of_unity);
+        if !pairings_verify(
+            tau_g1.into(),
+            self.g2_points[0],
+            G1Affine::generator(),
+            self.g2_points[1],
+        ) {
+            return Err(KzgError::InvalidTrustedSetup(
+                "The G1 and G2 points do not share the same tau".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Builds settings from points already in memory, in the layout of the fields: the roots of
+    /// unity and the G1 Lagrange points in bit-reversed order, and the G2 monomial points.
+    pub fn from_owned(
+        roots_of_unity: Vec<Scalar>,
+        g1_points: Vec<G1Affine>,
+        g2_points: Vec<G2Affine>,
+    ) -> Result<Self, KzgError> {
+        for (name, len, expected) in [
+            ("roots of unity", roots_of_unity.len(), NUM_ROOTS_OF_UNITY),
+            ("G1 points", g1_points.len(), NUM_G1_POINTS),
+            ("G2 points", g2_points.len(), NUM_G2_POINTS),
+        ] {
+            if len != expected {
+                return Err(KzgError::InvalidTrustedSetup(format!(
+                    "Expected {} {}, but got {}",
+                    expected, name, len
+                )));
+            }
+        }
+
+        let settings = Self {
+            roots_of_unity: Cow::Owned(roots_of_unity),
+            g1_points: Cow::Owned(g1_points),
+            g2_points: Cow::Owned(g2_points),
+        };
+        settings.validate()?;
+        Ok(settings)
+    }
+
+    /// Builds settings from any points, of any sizes, with none of the checks of
+    /// [`KzgSettings::from_owned`], for tests on small domains that can be checked by hand.
+    #[cfg(test)]
+    pub(crate) fn from_parts_unchecked(
+        roots_of_unity: Vec<Scalar>,
+        g1_points: Vec<G1Affine>,
+        g2_points: Vec<G2Affine>,
+    ) -> Self {
+        Self {
+            roots_of_unity: Cow::Owned(roots_of_unity),
+            g1_points: Cow::Owned(g1_points),
+            g2_points: Cow::Owned(g2_points),
+        }
+    }
+
+    /// Checks that every point is on its curve and in the prime-order subgroup, that
+    /// `g2_points[0]` is the G2 generator, and that the G1 points are not in monomial form.
+    ///
+    /// Decompressing a point already checks the first two, so this is for points that were
+    /// built another way, e.g. with the "unchecked" constructors.
+    pub fn validate(&self) -> Result<(), KzgError> {
+        if let Some(i) = self
+            .g1_points
+            .iter()
+            .position(|point| !bool::from(point.is_on_curve() & point.is_torsion_free()))
+        {
+            return Err(KzgError::InvalidTrustedSetup(format!(
+                "G1 point {} is not in the G1 subgroup",
+                i
+            )));
+        }
+        if let Some(i) = self
+            .g2_points
+            .iter()
+            .position(|point| !bool::from(point.is_on_curve() & point.is_torsion_free()))
+        {
+            return Err(KzgError::InvalidTrustedSetup(format!(
+                "G2 point {} is not in the G2 subgroup",
+                i
+            )));
+        }
+        if self.g2_points.first() != Some(&G2Affine::generator()) {
+            return Err(KzgError::InvalidTrustedSetup(
+                "The first G2 point is not the generator".to_string(),
+            ));
+        }
+
+        is_trusted_setup_in_lagrange_form(&self.g1_points, &self.g2_points)
+    }
+
+    /// Parses a trusted setup in the textual format of `trusted_setup.txt`, as used by c-kzg.
+    pub fn load_trusted_setup_from_str(contents: &str) -> Result<Self, KzgError> {
+        let (g1_points, g2_points) = parse_trusted_setup_with(contents, decompress_g1_points)?;
+        is_trusted_setup_in_lagrange_form(&g1_points, &g2_points)?;
+
+        Ok(Self {
+            roots_of_unity: Cow::Owned(compute_roots_of_unity(NUM_ROOTS_OF_UNITY)?),
+            g1_points: Cow::Owned(bit_reversal_permutation(&g1_points)?),
+            g2_points: Cow::Owned(g2_points),
+        })
+    }
+
+    /// Reads a trusted setup in the textual format of `trusted_setup.txt` at runtime.
+    #[cfg(feature = "std")]
+    pub fn load_trusted_setup_from_reader<R: std::io::Read>(
+        mut reader: R,
+    ) -> Result<Self, KzgError> {
+        let mut contents = alloc::string::String::new();
+        reader.read_to_string(&mut contents).map_err(|e| {
+            KzgError::InvalidTrustedSetup(format!("Failed to read the trusted setup: {}", e))
+        })?;
+        Self::load_trusted_setup_from_str(&contents)
+    }
+
+    /// The G1 point at index `i`, in the bit-reversed order of the blob.
+    pub fn g1_point(&self, i: usize) -> Result<&G1Affine, KzgError> {
+        point_at(&self.g1_points, i, "G1 points")
+    }
+
+    /// The G2 point at index `i`, `[tau^i]G2`.
+    pub fn g2_point(&self, i: usize) -> Result<&G2Affine, KzgError> {
+        point_at(&self.g2_points, i, "G2 points")
+    }
+
+    /// The root of unity at index `i`, in the bit-reversed order of the blob.
+    pub fn root_of_unity(&self, i: usize) -> Result<&Scalar, KzgError> {
+        point_at(&self.roots_of_unity, i, "roots of unity")
+    }
+
+    /// `[tau]G2`, the point that every pairing check verifies proofs against.
+    pub fn g2_secret_generator(&self) -> Result<&G2Affine, KzgError> {
+        self.g2_point(1)
+    }
+
+    /// Returns the Lagrange-form point of the domain element `w^logical_index`, where `w` is the
+    /// primitive root of unity. The points are stored in bit-reversed order like the blob, so
+    /// blob element `j` pairs with `g1_points[j]` directly, while `logical_index` is the bit
+    /// reversal of `j`.
+    pub fn g1_point_for_element(&self, logical_index: usize) -> Result<&G1Affine, KzgError> {
+        if logical_index >= NUM_FIELD_ELEMENTS_PER_BLOB {
+            return Err(KzgError::BadArgs(format!(
+                "The index {} is out of range, the blob has {} elements",
+                logical_index, NUM_FIELD_ELEMENTS_PER_BLOB
+            )));
+        }
+
+        let log_n = NUM_FIELD_ELEMENTS_PER_BLOB.trailing_zeros();
+        self.g1_point(logical_index.reverse_bits() >> (usize::BITS - log_n))
+    }
+
+    /// Writes the setup in the in-memory layout that `build.rs` dumps to its `.bin` files,
+    /// after [`SETUP_BINARY_MAGIC`], [`SETUP_BINARY_VERSION`] and the three point counts as
+    /// little-endian `u64`s. Reloading it with [`KzgSettings::deserialize_from`] skips parsing
+    /// and decompression, but the layout is only portable between builds of the same version on
+    /// the same kind of machine.
+    #[cfg(feature = "std")]
+    pub fn serialize_to<W: std::io::Write>(&self, mut writer: W) -> Result<(), KzgError> {
+        fn as_bytes<T>(values: &[T]) -> &[u8] {
+            // Safety: the points and scalars are plain arrays of limbs
+            unsafe { slice::from_raw_parts(values.as_ptr().cast(), core::mem::size_of_val(values)) }
+        }
+
+        let mut header = Vec::with_capacity(SETUP_BINARY_HEADER_SIZE);
+        header.extend_from_slice(SETUP_BINARY_MAGIC);
+        header.push(SETUP_BINARY_VERSION);
+        for len in [
+            self.roots_of_unity.len(),
+            self.g1_points.len(),
+            self.g2_points.len(),
+        ] {
+            header.extend_from_slice(&(len as u64).to_le_bytes());
+        }
+
+        [
+            header.as_slice(),
+            as_bytes(&self.roots_of_unity),
+            as_bytes(&self.g1_points),
+            as_bytes(&self.g2_points),
+        ]
+        .iter()
+        .try_for_each(|bytes| writer.write_all(bytes))
+        .map_err(|e| KzgError::BadArgs(format!("Failed to write the trusted setup: {}", e)))
+    }
+
+    /// Reads a setup written by [`KzgSettings::serialize_to`]. The header must match and the
+    /// counts must be the mainnet sizes; then every point must re-encode to itself and pass
+    /// [`KzgSettings::validate`], the roots of unity must be the expected ones, and the G1 and
+    /// G2 points must share the same tau.
+    #[cfg(feature = "std")]
+    pub fn deserialize_from<R: std::io::Read>(mut reader: R) -> Result<Self, KzgError> {
+        fn read_values<T, R: std::io::Read>(reader: &mut R, n: usize) -> Result<Vec<T>, KzgError> {
+            let size = core::mem::size_of::<T>();
+            let mut bytes = vec![0u8; n * size];
+            read_setup_bytes(reader, &mut bytes)?;
+            // Safety: the chunks come from an untrusted reader, but `T` is only ever one of the
+            // limb arrays `Scalar`, `G1Affine` and `G2Affine`, and every value read here is
+            // checked for a canonical encoding before it is used
+            Ok(bytes
+                .chunks_exact(size)
+                .map(|chunk| unsafe { core::ptr::read_unaligned(chunk.as_ptr().cast::<T>()) })
+                .collect())
+        }
+
+        let mut header = [0u8; SETUP_BINARY_HEADER_SIZE];
+        read_setup_bytes(&mut reader, &mut header)?;
+        if &header[..SETUP_BINARY_MAGIC.len()] != SETUP_BINARY_MAGIC {
+            return Err(KzgError::InvalidTrustedSetup(
+                "The input is not a serialized trusted setup".to_string(),
+            ));
+        }
+        let version = header[SETUP_BINARY_MAGIC.len()];
+        if version != SETUP_BINARY_VERSION {
+            return Err(KzgError::InvalidTrustedSetup(format!(
+                "Unsupported trusted setup version {}, expected {}",
+                version, SETUP_BINARY_VERSION
+            )));
+        }
+        let counts = header[SETUP_BINARY_MAGIC.len() + 1..]
+            .chunks_exact(8)
+            .map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap()));
+        for ((count, expected), name) in counts
+            .zip([NUM_ROOTS_OF_UNITY, NUM_G1_POINTS, NUM_G2_POINTS])
+            .zip(["roots of unity", "G1 points", "G2 points"])
+        {
+            if count != expected as u64 {
+                return Err(KzgError::InvalidTrustedSetup(format!(
+                    "Expected {} {}, but got {}",
+                    expected, name, count
+                )));
+            }
+        }
+
+        let settings = Self {
+            roots_of_unity: Cow::Owned(read_values(&mut reader, NUM_ROOTS_OF_UNITY)?),
+            g1_points: Cow::Owned(read_values(&mut reader, NUM_G1_POINTS)?),
+            g2_points: Cow::Owned(read_values(&mut reader, NUM_G2_POINTS)?),
+        };
+
+        // The bytes come from an untrusted reader, so only accept points whose limbs are the
+        // canonical encoding of a valid point before doing any arithmetic with them
+        if let Some(i) = settings.g1_points.iter().position(|point| {
+            Option::<G1Affine>::from(G1Affine::from_compressed(&point.to_compressed()))
+                != Some(*point)
+        }) {
+            return Err(KzgError::InvalidTrustedSetup(format!(
+                "G1 point {} is not canonically encoded",
+                i
+            )));
+        }
+        if let Some(i) = settings.g2_points.iter().position(|point| {
+            Option::<G2Affine>::from(G2Affine::from_compressed(&point.to_compressed()))
+                != Some(*point)
+        }) {
+            return Err(KzgError::InvalidTrustedSetup(format!(
+                "G2 point {} is not canonically encoded",
+                i
+            )));
+        }
+        settings.validate()?;
+        if settings.roots_of_unity[..] != compute_roots_of_unity(NUM_ROOTS_OF_UNITY)?[..] {
+            return Err(KzgError::InvalidTrustedSetup(
+                "The roots of unity are not the expected ones".to_string(),
+            ));
+        }
+        settings.verify_shared_tau()?;
+
+        Ok(settings)
+    }
+
+    /// Serializes the setup into a single buffer: the roots of unity as big-endian field
+    /// elements, followed by the compressed G1 points and the compressed G2 points.
+    pub fn to_compressed_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(
+            self.roots_of_unity.len() * BYTES_PER_FIELD_ELEMENT
+                + self.g1_points.len() * BYTES_PER_G1_POINT
+                + self.g2_points.len() * BYTES_PER_G2_POINT,
+        );
+
+        for root in self.roots_of_unity.iter() {
+            bytes.extend_from_slice(&scalar_to_bytes32(root));
+        }
+
+        for point in self.g1_points.iter() {
+            bytes.extend_from_slice(&point.to_compressed());
+        }
+
+        for point in self.g2_points.iter() {
+            bytes.extend_from_slice(&point.to_compressed());
+        }
+
+        bytes
+    }
+
+    /// Loads a setup produced by [`KzgSettings::to_compressed_bytes`]. The roots of unity must
+    /// be the expected ones, every point must decompress into the prime-order subgroup, and the
+    /// result must pass [`KzgSettings::validate`].
+    pub fn from_compressed_bytes(bytes: &[u8]) -> Result<Self, KzgError> {
+        if bytes.len() != BYTES_PER_COMPRESSED_KZG_SETTINGS {
+            return Err(KzgError::InvalidBytesLength(format!(
+                "The compressed settings should be {} bytes, but were {}",
+                BYTES_PER_COMPRESSED_KZG_SETTINGS,
+                bytes.len(),
+            )));
+        }
+
+        let (roots_of_unity_bytes, points_bytes) =
+            bytes.split_at(NUM_ROOTS_OF_UNITY * BYTES_PER_FIELD_ELEMENT);
+        let (g1_bytes, g2_bytes) = points_bytes.split_at(NUM_G1_POINTS * BYTES_PER_G1_POINT);
+
+        let settings = Self {
+            roots_of_unity: Cow::Owned(decode_roots_of_unity(roots_of_unity_bytes)?),
+            g1_points: Cow::Owned(decode_g1_points(g1_bytes)?),
+            g2_points: Cow::Owned(decode_g2_points(g2_bytes)?),
+        };
+        if settings.roots_of_unity[..] != compute_roots_of_unity(NUM_ROOTS_OF_UNITY)?[..] {
+            return Err(KzgError::InvalidTrustedSetup(
+                "The roots of unity are not the expected ones".to_string(),
+            ));
+        }
+        settings.validate()?;
+
+        Ok(settings)
+    }
+}
+
+/// The first bytes of a setup written by [`KzgSettings::serialize_to`].
+#[cfg(feature = "std")]
+pub const SETUP_BINARY_MAGIC: &[u8; 8] = b"KZGSETUP";
+/// The version of the layout written by [`KzgSettings::serialize_to`].
+#[cfg(feature = "std")]
+pub const SETUP_BINARY_VERSION: u8 = 1;
+#[cfg(feature = "std")]
+const SETUP_BINARY_HEADER_SIZE: usize = SETUP_BINARY_MAGIC.len() + 1 + 3 * 8;
+
+#[cfg(feature = "std")]
+fn read_setup_bytes<R: std::io::Read>(reader: &mut R, bytes: &mut [u8]) -> Result<(), KzgError> {
+    reader.read_exact(bytes).map_err(|e| match e.kind() {
+        std::io::ErrorKind::UnexpectedEof => {
+            KzgError::InvalidBytesLength("The serialized trusted setup is truncated".to_string())
+        }
+        _ => KzgError::InvalidTrustedSetup(format!("Failed to read the trusted setup: {}", e)),
+    })
+}
+
+/// Parses the buffer of [`KzgSettings::to_compressed_bytes`]: the contents of the `.bin` files
+/// generated by `build.rs`, concatenated, with each value in its portable encoding.
+impl TryFrom<&[u8]> for KzgSettings {
+    type Error = KzgError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, KzgError> {
+        Self::from_compressed_bytes(bytes)
+    }
+}
+
+/// Builds a [`KzgSettings`] for verification from the G1 Lagrange points and the two G2 points
+/// `[G2, tau * G2]`, deriving the roots of unity.
+#[derive(Debug, Clone, Default)]
+pub struct KzgSettingsBuilder {
+    g1_points: Option<Vec<G1Affine>>,
+    g2_points: Option<[G2Affine; 2]>,
+}
+
+impl KzgSettingsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The G1 points in Lagrange form, in the order they appear in the trusted setup file.
+    pub fn g1_lagrange_points(mut self, g1_points: Vec<G1Affine>) -> Self {
+        self.g1_points = Some(g1_points);
+        self
+    }
+
+    /// The G2 generator followed by `tau` times the generator.
+    pub fn g2_points(mut self, g2_points: [G2Affine; 2]) -> Self {
+        self.g2_points = Some(g2_points);
+        self
+    }
+
+    pub fn build(self) -> Result<KzgSettings, KzgError> {
+        let g1_points = self
+            .g1_points
+            .ok_or_else(|| KzgError::BadArgs("Missing G1 points".to_string()))?;
+        let g2_points = self
+            .g2_points
+            .ok_or_else(|| KzgError::BadArgs("Missing G2 points".to_string()))?;
+
+        if g1_points.len() != NUM_G1_POINTS {
+            return Err(KzgError::InvalidTrustedSetup(format!(
+                "Expected {} G1 points, but got {}",
+                NUM_G1_POINTS,
+                g1_points.len()
+            )));
+        }
+
+        is_trusted_setup_in_lagrange_form(&g1_points, &g2_points)?;
+
+        Ok(KzgSettings {
+            roots_of_unity: Cow::Owned(compute_roots_of_unity(NUM_ROOTS_OF_UNITY)?),
+            g1_points: Cow::Owned(bit_reversal_permutation(&g1_points)?),
+            g2_points: Cow::Owned(g2_points.to_vec()),
+        })
+    }
+}
+
+/// Returns `points[i]`, or an error naming the table instead of panicking.
+fn point_at<'a, T>(points: &'a [T], i: usize, name: &str) -> Result<&'a T, KzgError> {
+    points.get(i).ok_or_else(|| {
+        KzgError::BadArgs(format!(
+            "The index {} is out of range, the settings have {} {}",
+            i,
+            points.len(),
+            name
+        ))
+    })
+}
+
+fn decode_roots_of_unity(bytes: &[u8]) -> Result<Vec<Scalar>, KzgError> {
+    bytes
+        .chunks_exact(BYTES_PER_FIELD_ELEMENT)
+        .map(|chunk| {
+            Bytes32::from_slice(chunk).and_then(|bytes| safe_scalar_affine_from_bytes(&bytes))
+        })
+        .collect()
+}
+
+fn decode_g1_points(bytes: &[u8]) -> Result<Vec<G1Affine>, KzgError> {
+    let points = bytes
+        .chunks_exact(BYTES_PER_G1_POINT)
+        .map(|chunk| chunk.try_into().unwrap())
+        .collect::<Vec<_>>();
+    decompress_g1_points(&points)
+}
+
+/// Decompresses the G1 points of a setup, across threads with the `parallel` feature since each
+/// decompression needs a square root.
+fn decompress_g1_points(points: &[[u8; BYTES_PER_G1_POINT]]) -> Result<Vec<G1Affine>, KzgError> {
+    #[cfg(feature = "parallel")]
+    let g1_points = points
+        .par_iter()
+        .enumerate()
+        .map(decompress_g1_point)
+        .collect();
+    #[cfg(not(feature = "parallel"))]
+    let g1_points = points.iter().enumerate().map(decompress_g1_point).collect();
+    g1_points
+}
+
+fn decode_g2_points(bytes: &[u8]) -> Result<Vec<G2Affine>, KzgError> {
+    bytes
+        .chunks_exact(BYTES_PER_G2_POINT)
+        .map(|chunk| {
+            let g2 = G2Affine::from_compressed(chunk.try_into().unwrap());
+            if g2.is_none().into() {
+                return Err(KzgError::BadArgs(
+                    "Failed to parse G2Affine from bytes".to_string(),
+                ));
+            }
+            Ok(g2.unwrap())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kzg_proof::tests::{BlobInput, Input, Test};
+    use crate::test_files::{VERIFY_BLOB_KZG_PROOF_TESTS, VERIFY_KZG_PROOF_TESTS};
+    use crate::{Bytes48, KzgProof};
+    use bls12_381::{G1Projective, G2Projective};
+    use std::thread;
+
+    #[test]
+    fn test_get_g2_points_matches_trusted_setup() {
+        let g2_points = get_g2_points();
+        assert_eq!(g2_points.len(), NUM_G2_POINTS);
+
+        let (_, expected) =
+            crate::utils::parse_trusted_setup(include_str!("trusted_setup.txt")).unwrap();
+        assert_eq!(g2_points[NUM_G2_POINTS - 1], expected[NUM_G2_POINTS - 1]);
+        // `[s]G2`, used as `x` by `verify_kzg_proof`
+        assert_eq!(g2_points[1], expected[1]);
+        assert_eq!(get_kzg_settings().g2_points[1], expected[1]);
+    }
+
+    #[test]
+    fn test_decompress_g1_points_matches_serial() {
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+        let points = kzg_settings
+            .g1_points
+            .iter()
+            .map(G1Affine::to_compressed)
+            .collect::<Vec<_>>();
+
+        let serial = points
+            .iter()
+            .enumerate()
+            .map(decompress_g1_point)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(decompress_g1_points(&points).unwrap(), serial);
+        assert_eq!(serial, kzg_settings.g1_points.to_vec());
+
+        let mut bad_points = points;
+        bad_points[100] = [0xff; BYTES_PER_G1_POINT];
+        assert!(matches!(
+            decompress_g1_points(&bad_points),
+            Err(KzgError::BadArgs(message)) if message.contains("100")
+        ));
+    }
+
+    #[test]
+    fn test_compressed_bytes_round_trip() {
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+        let bytes = kzg_settings.to_compressed_bytes();
+        assert_eq!(bytes.len(), BYTES_PER_COMPRESSED_KZG_SETTINGS);
+
+        let reloaded = KzgSettings::from_compressed_bytes(&bytes).unwrap();
+        assert_eq!(reloaded, kzg_settings);
+        assert_eq!(
+            KzgSettings::try_from(bytes.as_slice()).unwrap(),
+            kzg_settings
+        );
+        assert!(matches!(
+            KzgSettings::try_from(&bytes[1..]),
+            Err(KzgError::InvalidBytesLength(_))
+        ));
+
+        for (_test_file, data) in VERIFY_BLOB_KZG_PROOF_TESTS {
+            let test: Test<BlobInput> = serde_yaml::from_str(data).unwrap();
+            let (Ok(blob), Ok(commitment), Ok(proof)) = (
+                test.input.get_blob(),
+                test.input.get_commitment(),
+                test.input.get_proof(),
+            ) else {
+                continue;
+            };
+
+            let result = KzgProof::verify_blob_kzg_proof(blob, &commitment, &proof, &reloaded);
+            match result {
+                Ok(result) => {
+                    assert_eq!(result, test.get_output().unwrap_or(false));
+                }
+                Err(_) => {
+                    assert!(test.get_output().is_none());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_g1_point_for_element() {
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+        // The primitive root sits at the bit reversal of index 1
+        let omega = kzg_settings.roots_of_unity[NUM_FIELD_ELEMENTS_PER_BLOB / 2];
+
+        for logical_index in [0, 1, 5, 2048, NUM_FIELD_ELEMENTS_PER_BLOB - 1] {
+            let root = omega.pow(&[logical_index as u64, 0, 0, 0]);
+            let j = kzg_settings
+                .roots_of_unity
+                .iter()
+                .position(|r| *r == root)
+                .unwrap();
+            assert_eq!(
+                kzg_settings.g1_point_for_element(logical_index).unwrap(),
+                &kzg_settings.g1_points[j]
+            );
+        }
+        assert!(matches!(
+            kzg_settings.g1_point_for_element(NUM_FIELD_ELEMENTS_PER_BLOB),
+            Err(KzgError::BadArgs(_))
+        ));
+
+        // Settings without their G1 points error instead of panicking
+        let verify_only = KzgSettings::from_parts_unchecked(
+            kzg_settings.roots_of_unity.to_vec(),
+            Vec::new(),
+            kzg_settings.g2_points.to_vec(),
+        );
+        assert!(matches!(
+            verify_only.g1_point_for_element(1),
+            Err(KzgError::BadArgs(_))
+        ));
+    }
+
+    #[test]
+    fn test_point_accessors() {
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+        assert_eq!(
+            kzg_settings.g1_point(7).unwrap(),
+            &kzg_settings.g1_points[7]
+        );
+        assert_eq!(kzg_settings.g2_point(0).unwrap(), &G2Affine::generator());
+        assert_eq!(
+            kzg_settings.root_of_unity(7).unwrap(),
+            &kzg_settings.roots_of_unity[7]
+        );
+        assert_eq!(
+            kzg_settings.g2_secret_generator().unwrap(),
+            &kzg_settings.g2_points[1]
+        );
+
+        assert!(matches!(
+            kzg_settings.g1_point(NUM_G1_POINTS),
+            Err(KzgError::BadArgs(_))
+        ));
+        assert!(matches!(
+            kzg_settings.g2_point(NUM_G2_POINTS),
+            Err(KzgError::BadArgs(_))
+        ));
+        assert!(matches!(
+            kzg_settings.root_of_unity(NUM_ROOTS_OF_UNITY),
+            Err(KzgError::BadArgs(_))
+        ));
+
+        // A truncated setup errors instead of panicking in verification
+        let truncated = KzgSettings::from_parts_unchecked(
+            kzg_settings.roots_of_unity.to_vec(),
+            kzg_settings.g1_points.to_vec(),
+            vec![G2Affine::generator()],
+        );
+        assert!(truncated.g2_secret_generator().is_err());
+        let zero = Bytes32::from([0; 32]);
+        let generator = Bytes48::from(G1Affine::generator().to_compressed());
+        assert!(
+            KzgProof::verify_kzg_proof(&generator, &zero, &zero, &generator, &truncated).is_err()
+        );
+
+        let truncated = KzgSettings::from_parts_unchecked(
+            kzg_settings.roots_of_unity[..NUM_ROOTS_OF_UNITY / 2].to_vec(),
+            kzg_settings.g1_points.to_vec(),
+            kzg_settings.g2_points.to_vec(),
+        );
+        let blob = crate::Blob::from_field_elements(&[Scalar::one(); NUM_FIELD_ELEMENTS_PER_BLOB])
+            .unwrap();
+        let z = Bytes32::from([7; 32]);
+        assert!(matches!(
+            KzgProof::compute_kzg_proof(&blob, &z, &truncated),
+            Err(KzgError::InvalidTrustedSetup(_))
+        ));
+        assert!(matches!(
+            crate::kzg_proof::evaluate_polynomial_in_evaluation_form(
+                &[Scalar::one(); NUM_FIELD_ELEMENTS_PER_BLOB],
+                Scalar::from(7),
+                &truncated,
+            ),
+            Err(KzgError::InvalidTrustedSetup(_))
+        ));
+    }
+
+    #[test]
+    fn test_custom_settings_are_freed() {
+        let owned = KzgSettings::from_compressed_bytes(
+            &KzgSettings::load_trusted_setup_file()
+                .unwrap()
+                .to_compressed_bytes(),
+        )
+        .unwrap();
+        assert!(matches!(owned.g1_points, Cow::Owned(_)));
+        let data = include_str!("../tests/verify_kzg_proof/verify_kzg_proof_case_correct_proof_02e696ada7d4631d/data.yaml");
+        let test: Test<Input> = serde_yaml::from_str(data).unwrap();
+
+        for _ in 0..64 {
+            let settings = EnvKzgSettings::from(owned.clone());
+            let EnvKzgSettings::Custom(arc) = &settings else {
+                unreachable!()
+            };
+            let weak = Arc::downgrade(arc);
+            assert!(KzgProof::verify_kzg_proof(
+                &test.input.get_commitment().unwrap(),
+                &test.input.get_z().unwrap(),
+                &test.input.get_y().unwrap(),
+                &test.input.get_proof().unwrap(),
+                settings.get(),
+            )
+            .unwrap());
+
+            let shared = settings.clone();
+            drop(settings);
+            assert!(weak.upgrade().is_some());
+            drop(shared);
+            // The last handle is gone, and the points with it
+            assert!(weak.upgrade().is_none());
+        }
+    }
+
+    #[test]
+    fn test_settings_equality() {
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+        assert_eq!(kzg_settings, get_kzg_settings());
+
+        // An owned copy is compared by content
+        let mut owned = KzgSettings {
+            roots_of_unity: Cow::Owned(kzg_settings.roots_of_unity.to_vec()),
+            g1_points: Cow::Owned(kzg_settings.g1_points.to_vec()),
+            g2_points: Cow::Owned(kzg_settings.g2_points.to_vec()),
+        };
+        assert_eq!(owned, kzg_settings);
+
+        owned.g2_points.to_mut()[1] = G2Affine::generator();
+        assert_ne!(owned, kzg_settings);
+    }
+
+    #[test]
+    fn test_from_compressed_bytes_rejects_bad_input() {
+        let bytes = KzgSettings::load_trusted_setup_file()
+            .unwrap()
+            .to_compressed_bytes();
+
+        assert!(KzgSettings::from_compressed_bytes(&bytes[1..]).is_err());
+
+        // A root of unity that is not a canonical field element
+        let mut corrupted = bytes.clone();
+        corrupted[..BYTES_PER_FIELD_ELEMENT].fill(0xff);
+        assert!(KzgSettings::from_compressed_bytes(&corrupted).is_err());
+
+        // Two roots of unity swapped, which permutes the domain
+        let mut swapped = bytes.clone();
+        let (first, second) = swapped[BYTES_PER_FIELD_ELEMENT..3 * BYTES_PER_FIELD_ELEMENT]
+            .split_at_mut(BYTES_PER_FIELD_ELEMENT);
+        first.swap_with_slice(second);
+        assert!(matches!(
+            KzgSettings::from_compressed_bytes(&swapped),
+            Err(KzgError::InvalidTrustedSetup(_))
+        ));
+
+        // A first G2 point that is not the generator
+        let g2_offset =
+            NUM_ROOTS_OF_UNITY * BYTES_PER_FIELD_ELEMENT + NUM_G1_POINTS * BYTES_PER_G1_POINT;
+        let mut swapped = bytes.clone();
+        let (first, second) =
+            swapped[g2_offset..g2_offset + 2 * BYTES_PER_G2_POINT].split_at_mut(BYTES_PER_G2_POINT);
+        first.swap_with_slice(second);
+        assert!(matches!(
+            KzgSettings::from_compressed_bytes(&swapped),
+            Err(KzgError::InvalidTrustedSetup(_))
+        ));
+
+        // A G1 point with the compression flag cleared
+        let mut corrupted = bytes;
+        corrupted[NUM_ROOTS_OF_UNITY * BYTES_PER_FIELD_ELEMENT] &= 0x7f;
+        assert!(KzgSettings::from_compressed_bytes(&corrupted).is_err());
+    }
+
+    #[test]
+    fn test_multiple_custom_settings() {
+        let mainnet = KzgSettings::load_trusted_setup_file().unwrap();
+
+        // A second setup whose G2 secret is a known value, so that proofs for it can be built
+        // directly from the secret.
+        let secret = Scalar::from(1337u64);
+        let mut g2_points = mainnet.g2_points.to_vec();
+        g2_points[1] = (G2Projective::generator() * secret).into();
+        let custom = KzgSettings {
+            g2_points: Cow::Owned(g2_points),
+            ..mainnet.clone()
+        };
+
+        let mainnet = EnvKzgSettings::from(mainnet);
+        let custom = EnvKzgSettings::from(custom);
+        assert_ne!(mainnet, custom);
+        assert_ne!(mainnet.get(), custom.get());
+
+        // p(secret) = 42, p(z) = y, so the proof is (42 - y) / (secret - z) * G1
+        let z = Scalar::from(5u64);
+        let y = Scalar::from(7u64);
+        let commitment = G1Affine::from(G1Affine::generator() * Scalar::from(42u64));
+        let quotient = (Scalar::from(42u64) - y) * (secret - z).invert().unwrap();
+        let proof = G1Affine::from(G1Affine::generator() * quotient);
+        let custom_proof = (
+            Bytes48::from_slice(&commitment.to_compressed()).unwrap(),
+            scalar_to_bytes32(&z),
+            scalar_to_bytes32(&y),
+            Bytes48::from_slice(&proof.to_compressed()).unwrap(),
+        );
+
+        let mainnet_proofs = VERIFY_KZG_PROOF_TESTS
+            .iter()
+            .filter_map(|(_test_file, data)| {
+                let test: Test<Input> = serde_yaml::from_str(data).unwrap();
+                let proof = test.input.get_proof().ok()?;
+                // Proofs at infinity open constant polynomials and are valid under any setup
+                if test.get_output() != Some(true) || proof.as_slice()[0] == 0xc0 {
+                    return None;
+                }
+                Some((
+                    test.input.get_commitment().unwrap(),
+                    test.input.get_z().unwrap(),
+                    test.input.get_y().unwrap(),
+                    proof,
+                ))
+            })
+            .collect::<Vec<_>>();
+        assert!(!mainnet_proofs.is_empty());
+
+        // Each thread verifies every proof against its own setup
+        let handles = [(mainnet, true), (custom, false)]
+            .into_iter()
+            .map(|(settings, is_mainnet)| {
+                let mainnet_proofs = mainnet_proofs.clone();
+                let custom_proof = custom_proof.clone();
+                thread::spawn(move || {
+                    let kzg_settings = settings.get();
+                    for (commitment, z, y, proof) in mainnet_proofs.iter().chain([&custom_proof]) {
+                        let result =
+                            KzgProof::verify_kzg_proof(commitment, z, y, proof, kzg_settings)
+                                .unwrap();
+                        let is_custom_proof = commitment.as_slice() == custom_proof.0.as_slice();
+                        assert_eq!(result, is_mainnet != is_custom_proof);
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_kzg_settings_builder() {
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+        let g1_lagrange_points = bit_reversal_permutation(&kzg_settings.g1_points).unwrap();
+        let g2_points = [kzg_settings.g2_points[0], kzg_settings.g2_points[1]];
+
+        let built = KzgSettingsBuilder::new()
+            .g1_lagrange_points(g1_lagrange_points.clone())
+            .g2_points(g2_points)
+            .build()
+            .unwrap();
+        assert_eq!(built.roots_of_unity, kzg_settings.roots_of_unity);
+        assert_eq!(built.g1_points, kzg_settings.g1_points);
+        assert_eq!(built.g2_points.len(), 2);
+
+        for (_test_file, data) in VERIFY_BLOB_KZG_PROOF_TESTS {
+            let test: Test<BlobInput> = serde_yaml::from_str(data).unwrap();
+            let (Ok(blob), Ok(commitment), Ok(proof)) = (
+                test.input.get_blob(),
+                test.input.get_commitment(),
+                test.input.get_proof(),
+            ) else {
+                continue;
+            };
+
+            let result = KzgProof::verify_blob_kzg_proof(blob, &commitment, &proof, &built);
+            assert_eq!(result.ok(), test.get_output());
+        }
+
+        assert!(KzgSettingsBuilder::new()
+            .g2_points(g2_points)
+            .build()
+            .is_err());
+        assert!(KzgSettingsBuilder::new()
+            .g1_lagrange_points(g1_lagrange_points[..2].to_vec())
+            .g2_points(g2_points)
+            .build()
+            .is_err());
+
+        // The monomial points [G1, 2 * G1, 4 * G1, ...] for tau = 2 are rejected
+        let mut monomial_points = Vec::with_capacity(NUM_G1_POINTS);
+        let mut point = G1Projective::generator();
+        for _ in 0..NUM_G1_POINTS {
+            monomial_points.push(point.into());
+            point = point.double();
+        }
+        let result = KzgSettingsBuilder::new()
+            .g1_lagrange_points(monomial_points)
+            .g2_points([
+                G2Affine::generator(),
+                (G2Projective::generator() * Scalar::from(2u64)).into(),
+            ])
+            .build();
+        assert!(matches!(result, Err(KzgError::InvalidTrustedSetup(_))));
+    }
+
+    #[test]
+    fn test_load_trusted_setup_from_str_point_counts() {
+        let contents = include_str!("trusted_setup.txt");
+        let lines = contents.lines().collect::<Vec<_>>();
+        let g1_lines = &lines[2..2 + NUM_G1_POINTS];
+        let g2_lines = &lines[2 + NUM_G1_POINTS..];
+
+        // The header claims the expected counts, but points are missing or extra, so the
+        // remaining points would be misread rather than padded with the identity
+        let with_points = |g1: &[&str], g2: &[&str]| {
+            format!(
+                "{}\n{}\n{}\n{}",
+                NUM_G1_POINTS,
+                NUM_G2_POINTS,
+                g1.join("\n"),
+                g2.join("\n")
+            )
+        };
+        for contents in [
+            with_points(&g1_lines[1..], g2_lines),
+            with_points(g1_lines, &g2_lines[1..]),
+            with_points(&[g1_lines, &g1_lines[..1]].concat(), g2_lines),
+        ] {
+            assert!(matches!(
+                KzgSettings::load_trusted_setup_from_str(&contents),
+                Err(KzgError::InvalidTrustedSetup(_))
+            ));
+        }
+
+        // The header matches the points, but not the compile-time sizes
+        let fewer = format!(
+            "{}\n{}\n{}\n{}",
+            NUM_G1_POINTS - 1,
+            NUM_G2_POINTS,
+            g1_lines[1..].join("\n"),
+            g2_lines.join("\n")
+        );
+        assert!(matches!(
+            KzgSettings::load_trusted_setup_from_str(&fewer),
+            Err(KzgError::InvalidTrustedSetup(_))
+        ));
+    }
+
+    #[test]
+    fn test_from_owned() {
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+        let roots_of_unity = kzg_settings.roots_of_unity.to_vec();
+        let g1_points = kzg_settings.g1_points.to_vec();
+        let g2_points = kzg_settings.g2_points.to_vec();
+
+        let owned =
+            KzgSettings::from_owned(roots_of_unity.clone(), g1_points.clone(), g2_points.clone())
+                .unwrap();
+        assert_eq!(owned, kzg_settings);
+        assert!(matches!(owned.g1_points, Cow::Owned(_)));
+
+        assert!(matches!(
+            KzgSettings::from_owned(
+                roots_of_unity.clone(),
+                g1_points[1..].to_vec(),
+                g2_points.clone()
+            ),
+            Err(KzgError::InvalidTrustedSetup(_))
+        ));
+
+        // G1 points in monomial form fail the pairing check
+        let tau = Scalar::from(42);
+        let mut monomial_points = g1_points;
+        monomial_points[0] = G1Affine::generator();
+        monomial_points[1] = (G1Affine::generator() * tau).into();
+        let mut g2_points = g2_points;
+        g2_points[1] = (G2Affine::generator() * tau).into();
+        assert!(matches!(
+            KzgSettings::from_owned(roots_of_unity, monomial_points, g2_points),
+            Err(KzgError::InvalidTrustedSetup(_))
+        ));
+    }
+
+    #[test]
+    fn test_validate() {
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+        kzg_settings.validate().unwrap();
+
+        // A point on the curve, but outside of the prime-order subgroup
+        let outside_subgroup = (1u8..)
+            .find_map(|x| {
+                let mut bytes = [0u8; BYTES_PER_G1_POINT];
+                bytes[0] = 0x80;
+                bytes[BYTES_PER_G1_POINT - 1] = x;
+                Option::from(G1Affine::from_compressed_unchecked(&bytes))
+                    .filter(|point: &G1Affine| !bool::from(point.is_torsion_free()))
+            })
+            .unwrap();
+        let mut corrupted = kzg_settings.clone();
+        corrupted.g1_points.to_mut()[100] = outside_subgroup;
+        assert!(matches!(
+            corrupted.validate(),
+            Err(KzgError::InvalidTrustedSetup(_))
+        ));
+        assert!(matches!(
+            KzgSettings::from_owned(
+                corrupted.roots_of_unity.to_vec(),
+                corrupted.g1_points.to_vec(),
+                corrupted.g2_points.to_vec()
+            ),
+            Err(KzgError::InvalidTrustedSetup(_))
+        ));
+
+        let mut corrupted = kzg_settings.clone();
+        corrupted.g2_points.to_mut()[0] = kzg_settings.g2_points[1];
+        assert!(matches!(
+            corrupted.validate(),
+            Err(KzgError::InvalidTrustedSetup(_))
+        ));
+    }
+
+    #[test]
+    fn test_verify_mainnet_setup() {
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+        kzg_settings.verify_mainnet_setup().unwrap();
+
+        // The pinned points still match, but the G1 points are no longer a Lagrange basis
+        let mut swapped = kzg_settings.clone();
+        swapped.g1_points.to_mut().swap(1, 2);
+        assert!(matches!(
+            swapped.verify_mainnet_setup(),
+            Err(KzgError::InvalidTrustedSetup(_))
+        ));
+
+        let mut corrupted = kzg_settings.clone();
+        corrupted.g2_points.to_mut()[1] = G2Affine::generator();
+        assert!(matches!(
+            corrupted.verify_mainnet_setup(),
+            Err(KzgError::InvalidTrustedSetup(_))
+        ));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_serialize_round_trip() {
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+        let mut bytes = Vec::new();
+        kzg_settings.serialize_to(&mut bytes).unwrap();
+
+        let reloaded = KzgSettings::deserialize_from(bytes.as_slice()).unwrap();
+        assert_eq!(reloaded, kzg_settings);
+        for (_test_file, data) in VERIFY_KZG_PROOF_TESTS {
+            let test: Test<Input> = serde_yaml::from_str(data).unwrap();
+            if test.get_output() != Some(true) {
+                continue;
+            }
+            assert!(KzgProof::verify_kzg_proof(
+                &test.input.get_commitment().unwrap(),
+                &test.input.get_z().unwrap(),
+                &test.input.get_y().unwrap(),
+                &test.input.get_proof().unwrap(),
+                &reloaded,
+            )
+            .unwrap());
+        }
+
+        let mut bad_magic = bytes.clone();
+        bad_magic[0] ^= 1;
+        let mut bad_version = bytes.clone();
+        bad_version[SETUP_BINARY_MAGIC.len()] += 1;
+        let mut bad_count = bytes.clone();
+        bad_count[SETUP_BINARY_MAGIC.len() + 1] ^= 1;
+        for bytes in [bad_magic, bad_version, bad_count] {
+            assert!(matches!(
+                KzgSettings::deserialize_from(bytes.as_slice()),
+                Err(KzgError::InvalidTrustedSetup(_))
+            ));
+        }
+        assert!(matches!(
+            KzgSettings::deserialize_from(&bytes[..bytes.len() - 1]),
+            Err(KzgError::InvalidBytesLength(_))
+        ));
+
+        // A G1 coordinate that is not a reduced field element
+        let mut unreduced = bytes.clone();
+        let g1_offset =
+            SETUP_BINARY_HEADER_SIZE + NUM_ROOTS_OF_UNITY * core::mem::size_of::<Scalar>();
+        unreduced[g1_offset..g1_offset + BYTES_PER_FIELD_ELEMENT + 16].fill(0xff);
+        assert!(matches!(
+            KzgSettings::deserialize_from(unreduced.as_slice()),
+            Err(KzgError::InvalidTrustedSetup(_))
+        ));
+
+        // Points that no longer share the same tau
+        let mut swapped = kzg_settings.clone();
+        swapped.g2_points.to_mut().swap(1, 2);
+        let mut swapped_bytes = Vec::new();
+        swapped.serialize_to(&mut swapped_bytes).unwrap();
+        assert!(matches!(
+            KzgSettings::deserialize_from(swapped_bytes.as_slice()),
+            Err(KzgError::InvalidTrustedSetup(_))
+        ));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_load_from_path() {
+        let path =
+            std::env::temp_dir().join(format!("kzg-rs-trusted-setup-{}.txt", std::process::id()));
+        std::fs::write(&path, include_str!("trusted_setup.txt")).unwrap();
+
+        let loaded = EnvKzgSettings::load_from_path(Some(&path));
+        let missing = EnvKzgSettings::load_from_path(Some(&path.with_extension("missing")));
+        std::fs::remove_file(&path).unwrap();
+
+        let loaded = loaded.unwrap();
+        assert!(matches!(loaded, EnvKzgSettings::Custom(_)));
+        assert_eq!(
+            loaded.get(),
+            &KzgSettings::load_trusted_setup_file().unwrap()
+        );
+        for (_test_file, data) in VERIFY_KZG_PROOF_TESTS {
+            let test: Test<Input> = serde_yaml::from_str(data).unwrap();
+            if test.get_output() != Some(true) {
+                continue;
+            }
+            assert!(KzgProof::verify_kzg_proof(
+                &test.input.get_commitment().unwrap(),
+                &test.input.get_z().unwrap(),
+                &test.input.get_y().unwrap(),
+                &test.input.get_proof().unwrap(),
+                loaded.get(),
+            )
+            .unwrap());
+        }
+
+        assert!(matches!(missing, Err(KzgError::InvalidTrustedSetup(_))));
+        assert_eq!(
+            EnvKzgSettings::load_from_path(None).unwrap(),
+            EnvKzgSettings::Default
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_load_trusted_setup_from_reader() {
+        let contents = include_str!("trusted_setup.txt");
+        let loaded = KzgSettings::load_trusted_setup_from_reader(contents.as_bytes()).unwrap();
+        assert_eq!(loaded, KzgSettings::load_trusted_setup_file().unwrap());
+
+        let lines = contents.lines().collect::<Vec<_>>();
+        let malformed = [
+            String::new(),
+            lines[..100].join("\n"),
+            contents.replacen("4096", "4095", 1),
+            contents.replacen("a0413c0d", "a0413c0z", 1),
+            contents.replacen("a0413c0d", "a0413c0", 1),
+            format!("{}\n{}", contents, lines[2]),
+        ];
+        for contents in malformed {
+            assert!(KzgSettings::load_trusted_setup_from_reader(contents.as_bytes()).is_err());
+        }
     }
 }
@@ -0,0 +1,291 @@
+use crate::{
+    pairings_verify, KzgError, BYTES_PER_G1_POINT, BYTES_PER_G2_POINT, NUM_G1_POINTS,
+    NUM_G2_POINTS, SCALE2_ROOT_OF_UNITY,
+};
+
+use alloc::{string::ToString, vec::Vec};
+use bls12_381::{G1Affine, G2Affine, Scalar};
+
+/// Decodes a hex string, with or without a `0x` prefix. Only relies on the `alloc` half of the
+/// `hex` crate, so it is available in `no_std` builds.
+pub fn hex_to_bytes(hex_str: &str) -> Result<Vec<u8>, KzgError> {
+    let trimmed_str = hex_str.strip_prefix("0x").unwrap_or(hex_str);
+    hex::decode(trimmed_str)
+        .map_err(|e| KzgError::InvalidHexFormat(format!("Failed to decode hex: {}", e)))
+}
+
+/// Moves the element at index `i` to the bit-reversal of `i`. The length must be a power of two.
+pub fn bit_reversal_permutation<T: Copy>(array: &[T]) -> Result<Vec<T>, KzgError> {
+    let n = array.len();
+    if !n.is_power_of_two() {
+        return Err(KzgError::BadArgs("n must be a power of 2".to_string()));
+    }
+
+    let mut bit_reversed_permutation = array.to_vec();
+    if n == 1 {
+        return Ok(bit_reversed_permutation);
+    }
+
+    let unused_bit_len = n.leading_zeros();
+    for (i, item) in array.iter().enumerate() {
+        let r = i.reverse_bits() >> (unused_bit_len + 1);
+        bit_reversed_permutation[r] = *item;
+    }
+
+    Ok(bit_reversed_permutation)
+}
+
+/// Returns `[1, root, root^2, ..., 1]`, stopping at the first power equal to one.
+pub fn expand_root_of_unity(root: Scalar, width: usize) -> Result<Vec<Scalar>, KzgError> {
+    if width < 2 {
+        return Err(KzgError::BadArgs(
+            "The width must be greater or equal to 2".to_string(),
+        ));
+    }
+
+    let mut expanded = vec![Scalar::one(), root];
+
+    for _ in 2..=width {
+        let current = expanded.last().unwrap() * root;
+        expanded.push(current);
+        if current == Scalar::one() {
+            break;
+        }
+    }
+
+    if expanded.last().unwrap() != &Scalar::one() {
+        return Err(KzgError::InvalidBytesLength(
+            "The last element value should be equal to 1".to_string(),
+        ));
+    }
+
+    Ok(expanded)
+}
+
+/// Computes the `width` roots of unity of the domain of size `width`, in bit-reversed order.
+pub fn compute_roots_of_unity(width: usize) -> Result<Vec<Scalar>, KzgError> {
+    if !width.is_power_of_two() {
+        return Err(KzgError::BadArgs(
+            "The width must be a power of 2".to_string(),
+        ));
+    }
+
+    let max_scale = width.trailing_zeros() as usize;
+    if max_scale >= SCALE2_ROOT_OF_UNITY.len() {
+        return Err(KzgError::BadArgs(format!(
+            "The max scale should be lower than {}",
+            SCALE2_ROOT_OF_UNITY.len()
+        )));
+    }
+
+    let root_of_unity = Scalar::from_raw(SCALE2_ROOT_OF_UNITY[max_scale]);
+    let mut expanded_roots = expand_root_of_unity(root_of_unity, width)?;
+    if expanded_roots.len() != width + 1 {
+        return Err(KzgError::InternalError);
+    }
+    let _ = expanded_roots.pop();
+
+    bit_reversal_permutation(&expanded_roots)
+}
+
+/// Checks that the G1 points are not in monomial form, i.e. that they do not satisfy
+/// `e(g1[1], g2[0]) == e(g1[0], g2[1])`.
+pub fn is_trusted_setup_in_lagrange_form(
+    g1_points: &[G1Affine],
+    g2_points: &[G2Affine],
+) -> Result<(), KzgError> {
+    if g1_points.len() < 2 || g2_points.len() < 2 {
+        return Err(KzgError::BadArgs("invalid args".to_string()));
+    }
+
+    let is_monomial_form = pairings_verify(g1_points[1], g2_points[0], g1_points[0], g2_points[1]);
+    if is_monomial_form {
+        return Err(KzgError::InvalidTrustedSetup(
+            "The G1 points are in monomial form".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Decompresses and checks the G1 point at index `i` of a trusted setup.
+pub fn decompress_g1_point(
+    (i, bytes): (usize, &[u8; BYTES_PER_G1_POINT]),
+) -> Result<G1Affine, KzgError> {
+    let g1 = G1Affine::from_compressed(bytes);
+    if g1.is_none().into() {
+        return Err(KzgError::BadArgs(format!(
+            "Failed to parse G1 point at index {}",
+            i
+        )));
+    }
+    Ok(g1.unwrap())
+}
+
+/// Parses the textual trusted setup format: the number of G1 points and the number of G2 points
+/// on their own lines, then one hex-encoded compressed point per line, G1 points first. The G1
+/// points are returned in file order.
+///
+/// Shared with `build.rs`, which bakes the default setup into the binary.
+pub fn parse_trusted_setup(contents: &str) -> Result<(Vec<G1Affine>, Vec<G2Affine>), KzgError> {
+    parse_trusted_setup_with(contents, |points| {
+        points.iter().enumerate().map(decompress_g1_point).collect()
+    })
+}
+
+/// Like [`parse_trusted_setup`], decompressing the G1 points with `decompress_g1_points`.
+pub fn parse_trusted_setup_with<F>(
+    contents: &str,
+    decompress_g1_points: F,
+) -> Result<(Vec<G1Affine>, Vec<G2Affine>), KzgError>
+where
+    F: FnOnce(&[[u8; BYTES_PER_G1_POINT]]) -> Result<Vec<G1Affine>, KzgError>,
+{
+    let mut lines = contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty());
+
+    let mut next_count = |name: &str| {
+        lines
+            .next()
+            .and_then(|line| line.parse::<usize>().ok())
+            .ok_or_else(|| {
+                KzgError::InvalidTrustedSetup(format!(
+                    "Missing or invalid number of {} points",
+                    name
+                ))
+            })
+    };
+    let num_g1_points = next_count("G1")?;
+    let num_g2_points = next_count("G2")?;
+
+    if num_g1_points != NUM_G1_POINTS || num_g2_points != NUM_G2_POINTS {
+        return Err(KzgError::InvalidTrustedSetup(format!(
+            "Expected {} G1 and {} G2 points, but the setup has {} and {}",
+            NUM_G1_POINTS, NUM_G2_POINTS, num_g1_points, num_g2_points
+        )));
+    }
+
+    let mut point_bytes = |expected_len: usize| {
+        let line = lines.next().ok_or_else(|| {
+            KzgError::InvalidTrustedSetup("The trusted setup is truncated".to_string())
+        })?;
+        let bytes = hex_to_bytes(line)?;
+        if bytes.len() != expected_len {
+            return Err(KzgError::InvalidTrustedSetup(format!(
+                "Expected a {} byte point, but got {} bytes",
+                expected_len,
+                bytes.len()
+            )));
+        }
+        Ok(bytes)
+    };
+
+    let g1_bytes = (0..num_g1_points)
+        .map(|_| {
+            let bytes = point_bytes(BYTES_PER_G1_POINT)?;
+            Ok(bytes.as_slice().try_into().unwrap())
+        })
+        .collect::<Result<Vec<_>, KzgError>>()?;
+    let g1_points = decompress_g1_points(&g1_bytes)?;
+
+    let g2_points = (0..num_g2_points)
+        .map(|i| {
+            let bytes = point_bytes(BYTES_PER_G2_POINT)?;
+            let g2 = G2Affine::from_compressed(bytes.as_slice().try_into().unwrap());
+            if g2.is_none().into() {
+                return Err(KzgError::BadArgs(format!(
+                    "Failed to parse G2 point at index {}",
+                    i
+                )));
+            }
+            Ok(g2.unwrap())
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if lines.next().is_some() {
+        return Err(KzgError::InvalidTrustedSetup(
+            "Unexpected data after the G2 points".to_string(),
+        ));
+    }
+
+    Ok((g1_points, g2_points))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hex_to_bytes() {
+        assert_eq!(hex_to_bytes("0x00ff10").unwrap(), vec![0x00, 0xff, 0x10]);
+        assert_eq!(hex_to_bytes("00FF10").unwrap(), vec![0x00, 0xff, 0x10]);
+        assert!(hex_to_bytes("").unwrap().is_empty());
+        assert!(hex_to_bytes("0x0").is_err());
+        assert!(hex_to_bytes("0xzz").is_err());
+    }
+
+    #[test]
+    fn test_bit_reversal_permutation() {
+        assert_eq!(bit_reversal_permutation(&[7]).unwrap(), vec![7]);
+        assert_eq!(
+            bit_reversal_permutation(&[0, 1, 2, 3, 4, 5, 6, 7]).unwrap(),
+            vec![0, 4, 2, 6, 1, 5, 3, 7]
+        );
+        assert!(bit_reversal_permutation(&[0, 1, 2]).is_err());
+    }
+
+    #[test]
+    fn test_compute_roots_of_unity() {
+        let roots = compute_roots_of_unity(crate::NUM_ROOTS_OF_UNITY).unwrap();
+        assert_eq!(roots, crate::get_roots_of_unity());
+
+        let roots = compute_roots_of_unity(4).unwrap();
+        assert_eq!(roots[0], Scalar::one());
+        assert_eq!(roots[1], -Scalar::one());
+        assert_eq!(roots[2] * roots[2], -Scalar::one());
+        assert_eq!(roots[3], -roots[2]);
+
+        assert!(compute_roots_of_unity(6).is_err());
+    }
+
+    #[test]
+    fn test_parse_trusted_setup_errors() {
+        let contents = include_str!("trusted_setup.txt");
+        let lines = contents.lines().collect::<Vec<_>>();
+
+        let (g1_points, g2_points) = parse_trusted_setup(contents).unwrap();
+        assert_eq!(g1_points.len(), NUM_G1_POINTS);
+        assert_eq!(g2_points.len(), NUM_G2_POINTS);
+
+        let truncated = lines[..100].join("\n");
+        assert!(matches!(
+            parse_trusted_setup(&truncated),
+            Err(KzgError::InvalidTrustedSetup(_))
+        ));
+
+        for counts in ["4095\n65", "4096\n64", "four\n65", "4096"] {
+            let contents = format!("{}\n{}", counts, lines[2..].join("\n"));
+            assert!(matches!(
+                parse_trusted_setup(&contents),
+                Err(KzgError::InvalidTrustedSetup(_))
+            ));
+        }
+
+        let garbage_hex = contents.replacen("a0413c0d", "a0413c0z", 1);
+        assert!(matches!(
+            parse_trusted_setup(&garbage_hex),
+            Err(KzgError::InvalidHexFormat(_))
+        ));
+
+        // A G1 point with the compression flag cleared
+        let mut bad_point = lines.clone();
+        let replaced = format!("20{}", &lines[3][2..]);
+        bad_point[3] = &replaced;
+        match parse_trusted_setup(&bad_point.join("\n")) {
+            Err(KzgError::BadArgs(message)) => assert!(message.contains("index 1")),
+            result => panic!("unexpected result {:?}", result.map(|_| ())),
+        }
+    }
+}
@@ -0,0 +1,135 @@
+//! Runs every consensus-spec KZG test vector found under `tests/`, rather than the subset embedded
+//! in the unit tests.
+
+use std::{fs, path::Path};
+
+use kzg_rs::{utils::hex_to_bytes, Blob, Bytes32, Bytes48, KzgError, KzgProof, KzgSettings};
+use serde::de::DeserializeOwned;
+use serde_derive::Deserialize;
+
+#[derive(Debug, Deserialize)]
+struct Test<I> {
+    input: I,
+    output: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+struct VerifyKzgProofInput {
+    commitment: String,
+    z: String,
+    y: String,
+    proof: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct VerifyBlobKzgProofInput {
+    blob: String,
+    commitment: String,
+    proof: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct VerifyBlobKzgProofBatchInput {
+    blobs: Vec<String>,
+    commitments: Vec<String>,
+    proofs: Vec<String>,
+}
+
+fn bytes32(hex_str: &str) -> Result<Bytes32, KzgError> {
+    Bytes32::from_slice(&hex_to_bytes(hex_str)?)
+}
+
+fn bytes48(hex_str: &str) -> Result<Bytes48, KzgError> {
+    Bytes48::from_slice(&hex_to_bytes(hex_str)?)
+}
+
+fn blob(hex_str: &str) -> Result<Blob, KzgError> {
+    Blob::from_slice(&hex_to_bytes(hex_str)?)
+}
+
+fn verify_kzg_proof(
+    input: &VerifyKzgProofInput,
+    kzg_settings: &KzgSettings,
+) -> Result<bool, KzgError> {
+    KzgProof::verify_kzg_proof(
+        &bytes48(&input.commitment)?,
+        &bytes32(&input.z)?,
+        &bytes32(&input.y)?,
+        &bytes48(&input.proof)?,
+        kzg_settings,
+    )
+}
+
+fn verify_blob_kzg_proof(
+    input: &VerifyBlobKzgProofInput,
+    kzg_settings: &KzgSettings,
+) -> Result<bool, KzgError> {
+    KzgProof::verify_blob_kzg_proof(
+        blob(&input.blob)?,
+        &bytes48(&input.commitment)?,
+        &bytes48(&input.proof)?,
+        kzg_settings,
+    )
+}
+
+fn verify_blob_kzg_proof_batch(
+    input: &VerifyBlobKzgProofBatchInput,
+    kzg_settings: &KzgSettings,
+) -> Result<bool, KzgError> {
+    KzgProof::verify_blob_kzg_proof_batch(
+        input
+            .blobs
+            .iter()
+            .map(|b| blob(b))
+            .collect::<Result<_, _>>()?,
+        input
+            .commitments
+            .iter()
+            .map(|c| bytes48(c))
+            .collect::<Result<_, _>>()?,
+        input
+            .proofs
+            .iter()
+            .map(|p| bytes48(p))
+            .collect::<Result<_, _>>()?,
+        kzg_settings,
+    )
+}
+
+/// Runs `run` on every `data.yaml` below `tests/<suite>` and returns the number of cases.
+fn run_suite<I: DeserializeOwned>(
+    suite: &str,
+    run: fn(&I, &KzgSettings) -> Result<bool, KzgError>,
+) -> usize {
+    let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+    let suite_dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join(suite);
+
+    let mut num_cases = 0;
+    for entry in fs::read_dir(&suite_dir).unwrap() {
+        let case_dir = entry.unwrap().path();
+        let data = fs::read_to_string(case_dir.join("data.yaml")).unwrap();
+        let test: Test<I> = serde_yaml::from_str(&data).unwrap();
+
+        let result = run(&test.input, &kzg_settings).ok();
+        assert_eq!(result, test.output, "{}", case_dir.display());
+        num_cases += 1;
+    }
+    num_cases
+}
+
+#[test]
+fn test_verify_kzg_proof_vectors() {
+    assert!(run_suite("verify_kzg_proof", verify_kzg_proof) > 0);
+}
+
+#[test]
+fn test_verify_blob_kzg_proof_vectors() {
+    assert!(run_suite("verify_blob_kzg_proof", verify_blob_kzg_proof) > 0);
+}
+
+#[test]
+fn test_verify_blob_kzg_proof_batch_vectors() {
+    assert!(run_suite("verify_blob_kzg_proof_batch", verify_blob_kzg_proof_batch) > 0);
+}
@@ -0,0 +1,12 @@
+//! Builds `kzg-rs` into a `#![no_std]` crate, so CI catches anything that pulls `std` back in:
+//!
+//! `cargo check --manifest-path tests/no_std/Cargo.toml --target riscv32imac-unknown-none-elf`
+#![no_std]
+
+use kzg_rs::{Blob, Bytes48, KzgError, KzgProof, KzgSettings};
+
+/// Verifies a blob proof against the trusted setup baked into `kzg-rs`.
+pub fn verify_blob(blob: Blob, commitment: &Bytes48, proof: &Bytes48) -> Result<bool, KzgError> {
+    let kzg_settings = KzgSettings::load_trusted_setup_file()?;
+    KzgProof::verify_blob_kzg_proof(blob, commitment, proof, &kzg_settings)
+}